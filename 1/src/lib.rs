@@ -0,0 +1,143 @@
+use aoc_utils::parsing::lines;
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
+use std::collections::HashMap;
+
+/// The location lists, one per whitespace separated column of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns {
+    columns: Vec<Vec<i64>>,
+}
+
+impl Columns {
+    /// Every line has to have the same number of columns, so the lists line
+    /// up.
+    pub fn parse(input: &str) -> Result<Columns, ParseError> {
+        let mut columns: Vec<Vec<i64>> = Vec::new();
+
+        for line in lines(input) {
+            let row = line.words::<i64>("a location ID")?;
+            if row.is_empty() {
+                continue;
+            }
+            if columns.is_empty() {
+                columns = vec![Vec::new(); row.len()];
+            }
+            if row.len() != columns.len() {
+                return Err(line
+                    .error("wrong number of columns")
+                    .expected(format!("{} location IDs", columns.len())));
+            }
+            for (column, num) in columns.iter_mut().zip(row) {
+                column.push(num);
+            }
+        }
+        Ok(Columns { columns })
+    }
+
+    pub fn count(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&[i64]> {
+        self.columns.get(index).map(Vec::as_slice)
+    }
+
+    /// Any two columns to compare, not necessarily next to each other.
+    pub fn pair(&self, left: usize, right: usize) -> Option<(&[i64], &[i64])> {
+        Some((self.get(left)?, self.get(right)?))
+    }
+}
+
+/// Pairs the smallest of each list, then the next smallest and so on, adding
+/// up how far apart each pair is.
+pub fn total_distance(left: &[i64], right: &[i64]) -> u64 {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+    left.sort();
+    right.sort();
+
+    left.iter()
+        .zip(right.iter())
+        .map(|(l, r)| l.abs_diff(*r))
+        .sum()
+}
+
+/// Adds up each number in `left` times the number of times it's in `right`.
+pub fn similarity_score(left: &[i64], right: &[i64]) -> i64 {
+    let mut occurrences: HashMap<i64, i64> = HashMap::new();
+
+    for r in right.iter() {
+        *occurrences.entry(*r).or_insert(0) += 1;
+    }
+
+    left.iter()
+        .map(|l| l * occurrences.get(l).copied().unwrap_or(0))
+        .sum()
+}
+
+const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Columns;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new("example", EXAMPLE).part1("11").part2("31")];
+
+    /// The puzzle compares the first two columns, so there have to be at
+    /// least two.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let columns = Columns::parse(input)?;
+        if columns.count() < 2 {
+            return Err(ParseError::new("not enough columns").expected("two lists of location IDs"));
+        }
+        Ok(columns)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (left, right) = input.pair(0, 1).expect("parse checks for two columns");
+        total_distance(left, right).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (left, right) = input.pair(0, 1).expect("parse checks for two columns");
+        similarity_score(left, right).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{similarity_score, total_distance, Columns, Day1};
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day1>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day1>();
+    }
+
+    #[test]
+    fn any_pair_of_columns() {
+        let columns = Columns::parse("3\t4 1\n4 3\t\t2\n2  5 3\n").unwrap();
+        assert_eq!(columns.count(), 3);
+        let (left, right) = columns.pair(2, 0).unwrap();
+        assert_eq!(total_distance(left, right), 3);
+        assert_eq!(similarity_score(left, right), 5);
+        assert!(columns.pair(0, 3).is_none());
+    }
+}
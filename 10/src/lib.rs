@@ -0,0 +1,117 @@
+use aoc_utils::search::{bfs_all, SearchResult};
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
+use aoc_utils::{Grid, Point};
+
+type Map = Grid<usize>;
+type Trails = SearchResult<Point, usize>;
+
+const TRAILHEAD: usize = 0;
+const SUMMIT: usize = 9;
+
+fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let map = Grid::parse(input, |char, _| {
+        char.to_digit(10)
+            .map(|height| height as usize)
+            .ok_or(format!("{:?} is not a height", char))
+    })?;
+    Ok(map)
+}
+
+fn uphill(map: &Map, position: Point) -> Vec<Point> {
+    let height = map[position];
+    map.neighbours4(position)
+        .filter(|(_, _, next_height)| **next_height == height + 1)
+        .map(|(_, next, _)| next)
+        .collect()
+}
+
+// Every step climbs by exactly one, so all trails from a trailhead are the same
+// length and a breadth-first search that keeps every predecessor finds them all.
+fn find_trails(map: &Map) -> Vec<Trails> {
+    map.iter()
+        .filter(|(_, height)| **height == TRAILHEAD)
+        .filter_map(|(trailhead, _)| {
+            bfs_all(
+                trailhead,
+                |position| uphill(map, *position),
+                |position| map[*position] == SUMMIT,
+            )
+        })
+        .collect()
+}
+
+fn count_trail_ends(trails: &[Trails]) -> usize {
+    trails.iter().map(|trail| trail.goals.len()).sum()
+}
+
+fn count_distinct_trails(trails: &[Trails]) -> usize {
+    trails
+        .iter()
+        .map(|trail| {
+            let predecessors = trail
+                .predecessors
+                .as_ref()
+                .expect("bfs_all keeps predecessors");
+            predecessors.count_paths(&trail.goals) as usize
+        })
+        .sum()
+}
+
+fn solution_1(trails: &[Trails]) -> u64 {
+    count_trail_ends(trails) as u64
+}
+
+fn solution_2(trails: &[Trails]) -> u64 {
+    count_distinct_trails(trails) as u64
+}
+
+const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Trails>;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new("example", EXAMPLE).part1("36").part2("81")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(find_trails(&parse_input(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solution_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solution_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day10;
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day10>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day10>();
+    }
+}
@@ -1,7 +1,7 @@
-use rayon::prelude::*;
+use aoc_utils::parsing::lines;
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
 use std::collections::HashMap;
-use std::fs::read_to_string;
-use std::time::Instant;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 struct Rock {
@@ -19,7 +19,7 @@ impl Rock {
         }
 
         let string_number = self.number.to_string();
-        if string_number.len() % 2 == 0 {
+        if string_number.len().is_multiple_of(2) {
             return Vec::from([
                 Rock::new(
                     string_number[string_number.len() / 2..string_number.len()]
@@ -39,7 +39,7 @@ impl Rock {
 }
 
 #[derive(Clone)]
-struct Rocks {
+pub struct Rocks {
     rocks: HashMap<Rock, u64>,
 }
 
@@ -62,21 +62,16 @@ impl Rocks {
     }
 }
 
-fn load_input(path: &str) -> Rocks {
+fn parse_input(input: &str) -> Result<Rocks, ParseError> {
     let mut rocks: Rocks = Rocks::new();
 
-    let raw_rocks = read_to_string(path).expect("failed to read file");
-    let rock_vector: Vec<Rock> = raw_rocks
-        .split_whitespace()
-        .map(|s| s.parse::<u64>().expect("failed to parse number"))
-        .map(Rock::new)
-        .collect();
-
-    for rock in rock_vector.iter() {
-        rocks.insert(*rock, 1);
+    for line in lines(input) {
+        for number in line.words("a number")? {
+            rocks.insert(Rock::new(number), 1);
+        }
     }
 
-    rocks
+    Ok(rocks)
 }
 
 fn blink_n(number_of_blinks: usize, mut rocks: Rocks) -> Rocks {
@@ -109,25 +104,44 @@ fn solution_2(rocks: Rocks) -> u64 {
     number_of_rocks_after_n_blinks(75, rocks)
 }
 
-fn main() {
-    let input_start = Instant::now();
-    let rocks: Rocks = load_input("input.dat");
-    println!("input took {:?}", input_start.elapsed());
-
-    let solution_1_start = Instant::now();
-    let output_1 = solution_1(rocks.clone());
-    println!(
-        "solution_1: {:?}, took {:?}",
-        output_1,
-        solution_1_start.elapsed()
-    );
-
-    // Too low 65601038650482
-    let solution_2_start = Instant::now();
-    let output_2 = solution_2(rocks.clone());
-    println!(
-        "solution_2: {:?}, took {:?}",
-        output_2,
-        solution_2_start.elapsed()
-    );
+const EXAMPLE: &str = "\
+125 17
+";
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Rocks;
+
+    const EXAMPLES: &'static [Example] = &[Example::new("example", EXAMPLE).part1("55312")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solution_1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solution_2(input.clone()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day11;
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day11>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day11>();
+    }
 }
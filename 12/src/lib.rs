@@ -0,0 +1,236 @@
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
+use aoc_utils::{Direction, Grid, Point};
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::Infallible;
+
+type GardenMap = Grid<char>;
+
+fn parse_input(input: &str) -> Result<GardenMap, ParseError> {
+    let garden_map = Grid::parse(input, |plant, _| Ok::<_, Infallible>(plant))?;
+    Ok(garden_map)
+}
+
+fn region_from_point(
+    garden_map: &GardenMap,
+    point: Point,
+    region_so_far: &mut BTreeSet<Point>,
+    perimeter_so_far: &mut BTreeMap<Point, Vec<Direction>>,
+) {
+    let plant = garden_map[point];
+
+    for direction in Direction::iter() {
+        let same_plant = point
+            .checked_step(direction)
+            .filter(|adjacent_point| garden_map.get(*adjacent_point) == Some(&plant));
+
+        match same_plant {
+            None => perimeter_so_far.entry(point).or_default().push(direction),
+            Some(adjacent_point) => {
+                if region_so_far.insert(adjacent_point) {
+                    region_from_point(garden_map, adjacent_point, region_so_far, perimeter_so_far);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Region {
+    contents: BTreeSet<Point>,
+    perimeter: BTreeMap<Point, Vec<Direction>>,
+    character: char,
+}
+
+impl Region {
+    fn new(garden_map: &GardenMap, point: Point) -> Region {
+        // Has to be the a top left index.
+        let mut contents: BTreeSet<Point> = BTreeSet::from([point]);
+        let mut perimeter: BTreeMap<Point, Vec<Direction>> = BTreeMap::new();
+        region_from_point(garden_map, point, &mut contents, &mut perimeter);
+        Region {
+            contents,
+            perimeter,
+            character: garden_map[point],
+        }
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        self.contents.contains(&point)
+    }
+
+    fn area(&self) -> u32 {
+        self.contents.len() as u32
+    }
+
+    fn fencing(&self) -> u32 {
+        let mut sum = 0;
+        for (_, fencing) in self.perimeter.iter() {
+            sum += fencing.len() as u32;
+        }
+        sum
+    }
+
+    fn sides(&self) -> u32 {
+        let mut sum = 0;
+        for (point, directions) in self.perimeter.iter() {
+            for direction in directions {
+                // A fence carries on the side of the plot to its left (or above,
+                // for fences running up and down) if that plot is fenced the same
+                // way, so only count the fences that start a side.
+                let along = match direction {
+                    Direction::Up | Direction::Down => Direction::Left,
+                    Direction::Left | Direction::Right => Direction::Up,
+                };
+                let continues_side = point
+                    .checked_step(along)
+                    .and_then(|previous| self.perimeter.get(&previous))
+                    .is_some_and(|previous| previous.contains(direction));
+                if !continues_side {
+                    sum += 1;
+                }
+            }
+        }
+        sum
+    }
+}
+fn find_regions(garden_map: &GardenMap) -> Vec<Region> {
+    let mut regions: Vec<Region> = Vec::new();
+    for point in garden_map.points() {
+        if !regions.iter().any(|region| region.contains(point)) {
+            regions.push(Region::new(garden_map, point));
+        }
+    }
+    regions
+}
+
+fn solution_1(regions: Vec<Region>) -> u32 {
+    let mut sum = 0;
+    for region in regions {
+        let area = region.area();
+        let fencing = region.fencing();
+        sum += area * fencing;
+    }
+    sum
+}
+
+fn solution_2(regions: Vec<Region>) -> u32 {
+    let mut sum = 0;
+    for region in regions {
+        let area = region.area();
+        let sides = region.sides();
+        sum += area * sides;
+    }
+    sum
+}
+
+fn visualise(regions: &[Region]) {
+    for region in regions {
+        println!(
+            "region {:?}, area {:?}, perimeter {:?}, sides {:?}",
+            region.character,
+            region.area(),
+            region.fencing(),
+            region.sides()
+        );
+    }
+}
+
+const EXAMPLE_1: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+const EXAMPLE_2: &str = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+
+const EXAMPLE_3: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+
+const EXAMPLE_4: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+const EXAMPLE_5: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Region>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("example 1", EXAMPLE_1)
+            .part1("140")
+            .part2("80"),
+        Example::new("example 2", EXAMPLE_2)
+            .part1("772")
+            .part2("436"),
+        Example::new("example 3", EXAMPLE_3).part2("236"),
+        Example::new("example 4", EXAMPLE_4).part2("368"),
+        Example::new("example 5", EXAMPLE_5)
+            .part1("1930")
+            .part2("1206"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(find_regions(&parse_input(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solution_1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solution_2(input.clone()).into()
+    }
+
+    fn visualise(input: &Self::Input) {
+        visualise(input);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day12;
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day12>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day12>();
+    }
+}
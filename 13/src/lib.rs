@@ -0,0 +1,179 @@
+use aoc_utils::parsing::{sections, Line, Pattern};
+use aoc_utils::testing::Example;
+use aoc_utils::Point;
+use aoc_utils::{Answer, ParseError, Solution};
+
+fn is_integer(num: f64) -> bool {
+    num.fract() == 0.0
+}
+#[derive(Debug, Copy, Clone)]
+pub struct Button {
+    x: usize,
+    y: usize,
+}
+
+impl Button {
+    fn new(x: usize, y: usize) -> Self {
+        Button { x, y }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Prize {
+    x: usize,
+    y: usize,
+}
+
+const BUTTON_A: Pattern = Pattern::new("Button A: X+{}, Y+{}");
+const BUTTON_B: Pattern = Pattern::new("Button B: X+{}, Y+{}");
+const PRIZE: Pattern = Pattern::new("Prize: X={}, Y={}");
+
+fn parse_input(input: &str) -> Result<Vec<(Prize, Button, Button)>, ParseError> {
+    let mut prizes: Vec<(Prize, Button, Button)> = Vec::new();
+
+    for machine in sections(input) {
+        let [button_a, button_b, prize] = machine[..] else {
+            return Err(machine[0]
+                .error("wrong number of lines for a machine")
+                .expected("button A, button B and the prize"));
+        };
+        let button_a = parse_coords(BUTTON_A, button_a)?;
+        let button_b = parse_coords(BUTTON_B, button_b)?;
+        let prize = parse_coords(PRIZE, prize)?;
+
+        prizes.push((
+            Prize {
+                x: prize.x,
+                y: prize.y,
+            },
+            Button::new(button_a.x, button_a.y),
+            Button::new(button_b.x, button_b.y),
+        ));
+    }
+
+    Ok(prizes)
+}
+
+fn parse_coords(pattern: Pattern, line: Line) -> Result<Point, ParseError> {
+    let coords = pattern.matches(line)?;
+    Ok(Point::new(
+        coords.parse(0, "a number")?,
+        coords.parse(1, "a number")?,
+    ))
+}
+
+fn get_button_press_solutions(
+    prize: Prize,
+    button_a: Button,
+    button_b: Button,
+) -> Option<(u64, u64)> {
+    let a = button_a.x as f64;
+    let b = button_b.x as f64;
+    let c = button_a.y as f64;
+    let d = button_b.y as f64;
+    let determinant = a * d - b * c;
+
+    if determinant == 0. {
+        return None;
+    }
+
+    let a_presses = (prize.x as f64 * d - prize.y as f64 * b) / determinant;
+    let b_presses = (prize.y as f64 * a - prize.x as f64 * c) / determinant;
+
+    if !is_integer(a_presses) || !is_integer(b_presses) {
+        return None;
+    }
+
+    Some((a_presses as u64, b_presses as u64))
+}
+
+fn solution_1(prizes: Vec<(Prize, Button, Button)>) -> u32 {
+    let mut sum = 0;
+    for machine in prizes.iter() {
+        let prize = machine.0;
+        let button_a = machine.1;
+        let button_b = machine.2;
+        sum += get_button_press_solutions(prize, button_a, button_b)
+            .iter()
+            .map(|(a, b)| (3 * *a) + *b)
+            .min()
+            .unwrap_or(0) as u32;
+    }
+    sum
+}
+
+const INCREMENT: usize = 10000000000000;
+
+fn solution_2(prizes: Vec<(Prize, Button, Button)>) -> u64 {
+    let mut sum = 0;
+    for machine in prizes.iter() {
+        let button_a = machine.1;
+        let button_b = machine.2;
+        let prize = Prize {
+            x: machine.0.x + INCREMENT,
+            y: machine.0.y + INCREMENT,
+        };
+        sum += get_button_press_solutions(prize, button_a, button_b)
+            .iter()
+            .map(|(a, b)| (3 * *a) + *b)
+            .min()
+            .unwrap_or(0);
+    }
+    sum
+}
+
+const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<(Prize, Button, Button)>;
+
+    const EXAMPLES: &'static [Example] = &[Example::new("example", EXAMPLE).part1("480")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solution_1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solution_2(input.clone()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day13;
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day13>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day13>();
+    }
+}
@@ -0,0 +1,258 @@
+use aoc_utils::image::{contact_sheet, Image};
+use aoc_utils::parsing::{lines, Line, Pattern};
+use aoc_utils::render::Colour;
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
+use aoc_utils::{Grid, Point, Vector};
+use std::collections::HashMap;
+use std::fs::create_dir_all;
+
+const ROBOT: Pattern = Pattern::new("p={},{} v={},{}");
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    position: Point,
+    velocity: Vector,
+}
+
+impl Robot {
+    fn new(x: usize, y: usize, vx: isize, vy: isize) -> Self {
+        Robot {
+            position: Point { x, y },
+            velocity: Vector { x: vx, y: vy },
+        }
+    }
+    fn from_line(line: Line) -> Result<Self, ParseError> {
+        let fields = ROBOT.matches(line)?;
+        let in_room = |index: usize, size: u8| {
+            let position: usize = fields.parse(index, "a position")?;
+            if position >= size as usize {
+                return Err(line
+                    .error_at(fields.get(index), "outside the room")
+                    .expected(format!("below {}", size)));
+            }
+            Ok(position)
+        };
+        Ok(Robot::new(
+            in_room(0, REAL_ROOM.0)?,
+            in_room(1, REAL_ROOM.1)?,
+            fields.parse(2, "a velocity")?,
+            fields.parse(3, "a velocity")?,
+        ))
+    }
+    fn proceed(&mut self, grid_width: u8, grid_height: u8) {
+        self.position = self
+            .position
+            .wrapping_add_mod(self.velocity, grid_width as usize, grid_height as usize)
+            .expect("grid should not be empty");
+    }
+}
+
+fn move_robots(robots: &mut Vec<Robot>, grid_width: u8, grid_height: u8) {
+    for robot in robots {
+        robot.proceed(grid_width, grid_height);
+    }
+}
+
+fn robot_frame(robots: &[Robot], grid_width: u8, grid_height: u8) -> Image {
+    let mut occupied = Grid::filled(grid_width as usize, grid_height as usize, false);
+    for robot in robots {
+        occupied[robot.position] = true;
+    }
+    Image::from_grid(&occupied, |occupied| match occupied {
+        true => Colour::GREEN,
+        false => Colour::WHITE,
+    })
+}
+
+/// The robots and the size of the room they're in.
+#[derive(Debug, Clone)]
+pub struct Room {
+    width: u8,
+    height: u8,
+    robots: Vec<Robot>,
+}
+
+const REAL_ROOM: (u8, u8) = (101, 103);
+const EXAMPLE_ROOM: (u8, u8) = (11, 7);
+
+// The input doesn't say how big the room is, so robots that all start inside
+// the example's room are taken to be in it, and any others in the real one.
+fn parse_input(input: &str) -> Result<Room, ParseError> {
+    let robots: Vec<Robot> = lines(input)
+        .map(Robot::from_line)
+        .collect::<Result<_, _>>()?;
+    let in_example_room = robots.iter().all(|robot| {
+        robot.position.x < EXAMPLE_ROOM.0 as usize && robot.position.y < EXAMPLE_ROOM.1 as usize
+    });
+    let (width, height) = match in_example_room {
+        true => EXAMPLE_ROOM,
+        false => REAL_ROOM,
+    };
+    Ok(Room {
+        width,
+        height,
+        robots,
+    })
+}
+
+#[derive(Hash, Eq, PartialEq, Debug)]
+enum Quadrant {
+    TopLeft,
+    BottomLeft,
+    TopRight,
+    BottomRight,
+    None,
+}
+
+impl Quadrant {
+    fn from_point(point: Point, grid_width: u8, grid_height: u8) -> Quadrant {
+        if point.x == (grid_width / 2) as usize || point.y == (grid_height / 2) as usize {
+            return Quadrant::None;
+        }
+        if (point.x as f32) < (grid_width as f32 / 2.)
+            && (point.y as f32) < (grid_height as f32 / 2.)
+        {
+            return Quadrant::TopLeft;
+        }
+        if (point.x as f32) < (grid_width as f32 / 2.)
+            && (point.y as f32) > (grid_height as f32 / 2.)
+        {
+            return Quadrant::BottomLeft;
+        }
+        if (point.x as f32) > (grid_width as f32 / 2.)
+            && (point.y as f32) < (grid_height as f32 / 2.)
+        {
+            return Quadrant::TopRight;
+        }
+        if (point.x as f32) > (grid_width as f32 / 2.)
+            && (point.y as f32) > (grid_height as f32 / 2.)
+        {
+            return Quadrant::BottomRight;
+        }
+        panic!("FAILED TO MAP {:?} to a quadrant", point);
+    }
+}
+
+fn solution_1(room: Room) -> u64 {
+    let Room {
+        width,
+        height,
+        mut robots,
+    } = room;
+    for _ in 0..100 {
+        move_robots(&mut robots, width, height);
+    }
+
+    let mut quadrant_count: HashMap<Quadrant, usize> = HashMap::new();
+
+    for robot in robots {
+        let quadrant = Quadrant::from_point(robot.position, width, height);
+        if quadrant == Quadrant::None {
+            continue;
+        }
+        quadrant_count
+            .entry(quadrant)
+            .and_modify(|count| *count += 1)
+            .or_insert(1);
+    }
+
+    let mut multiply: u64 = 1;
+    for (_, count) in quadrant_count.iter() {
+        multiply *= *count as u64;
+    }
+    multiply
+}
+
+const SECONDS: usize = 10000;
+// Frames per contact sheet, laid out in a square.
+const SHEET_FRAMES: usize = 100;
+const SHEET_COLUMNS: usize = 10;
+
+// Saves every second as a frame on a contact sheet in `out/`, a hundred to a
+// sheet, so the tree can be spotted by flicking through a hundred images.
+fn save_contact_sheets(room: Room) {
+    let Room {
+        width,
+        height,
+        mut robots,
+    } = room;
+    create_dir_all("out").unwrap();
+
+    let mut frames = Vec::with_capacity(SHEET_FRAMES);
+    for second in 0..SECONDS {
+        frames.push(robot_frame(&robots, width, height));
+        move_robots(&mut robots, width, height);
+
+        if frames.len() == SHEET_FRAMES || second == SECONDS - 1 {
+            let first_second = second + 1 - frames.len();
+            contact_sheet(&frames, SHEET_COLUMNS, 2, Colour::BLACK)
+                .save(format!("out/{}-{}.png", first_second, second))
+                .unwrap();
+            frames.clear();
+        }
+    }
+}
+
+const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Room;
+
+    const EXAMPLES: &'static [Example] = &[Example::new("example", EXAMPLE).part1("12")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solution_1(input.clone()).into()
+    }
+
+    // There's no part 2 solution: the tree has to be spotted by eye on the
+    // contact sheets.
+    fn visualise(input: &Self::Input) {
+        save_contact_sheets(input.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, Day14};
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day14>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day14>();
+    }
+
+    #[test]
+    fn room_size_from_the_robots() {
+        let room = parse_input("p=10,6 v=1,1\n").unwrap();
+        assert_eq!((room.width, room.height), (11, 7));
+        let room = parse_input("p=10,6 v=1,1\np=11,0 v=1,1\n").unwrap();
+        assert_eq!((room.width, room.height), (101, 103));
+    }
+}
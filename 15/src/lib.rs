@@ -0,0 +1,377 @@
+use aoc_utils::image::{save_gif, Image};
+use aoc_utils::parsing::sections;
+use aoc_utils::render::{CellGlyph, Colour};
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
+use aoc_utils::{Direction, Grid, Point};
+use std::fmt;
+use std::fs::create_dir_all;
+use std::iter;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum Coordinate {
+    Box,
+    Obstruction,
+    Robot,
+    Empty,
+    LeftBox,
+    RightBox,
+}
+
+impl CellGlyph for Coordinate {
+    fn glyph(&self) -> char {
+        match self {
+            Coordinate::Obstruction => '#',
+            Coordinate::LeftBox => '[',
+            Coordinate::RightBox => ']',
+            Coordinate::Box => 'O',
+            Coordinate::Robot => '@',
+            Coordinate::Empty => '.',
+        }
+    }
+
+    fn colour(&self) -> Option<Colour> {
+        match self {
+            Coordinate::Obstruction => Some(Colour::GREY),
+            Coordinate::Box | Coordinate::LeftBox | Coordinate::RightBox => Some(Colour::YELLOW),
+            Coordinate::Robot => Some(Colour::RED),
+            Coordinate::Empty => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Warehouse {
+    coordinates: Grid<Coordinate>,
+    robot: Point,
+}
+
+impl Warehouse {
+    fn new(coordinates: Grid<Coordinate>, robot: Point) -> Self {
+        Warehouse { coordinates, robot }
+    }
+
+    // Everything a push from `point` would shove along, both halves of a wide
+    // box together, paired with where each piece ends up. None if any of it
+    // would run into a wall or off the edge of the map.
+    fn pushed_by(&self, direction: Direction, point: Point) -> Option<Vec<(Point, Point)>> {
+        let mut pushed: Vec<(Point, Point)> = Vec::new();
+        let mut to_push = vec![point];
+
+        while let Some(point) = to_push.pop() {
+            if pushed.iter().any(|(from, _)| *from == point) {
+                continue;
+            }
+            match self.coordinates.get(point)? {
+                Coordinate::Empty => continue,
+                Coordinate::Obstruction => return None,
+                Coordinate::Box | Coordinate::Robot => {}
+                Coordinate::LeftBox => to_push.push(point.checked_step(Direction::Right)?),
+                Coordinate::RightBox => to_push.push(point.checked_step(Direction::Left)?),
+            }
+            let next = point.checked_step(direction)?;
+            pushed.push((point, next));
+            to_push.push(next);
+        }
+        Some(pushed)
+    }
+
+    fn move_robot(&mut self, direction: Direction) {
+        let Some(pushed) = self.pushed_by(direction, self.robot) else {
+            return;
+        };
+
+        // Lift everything before putting it down, so nothing is overwritten
+        // whatever order it was found in.
+        let moved: Vec<(Point, Coordinate)> = pushed
+            .iter()
+            .map(|(from, to)| (*to, self.coordinates[*from].clone()))
+            .collect();
+        for (from, _) in &pushed {
+            self.coordinates[*from] = Coordinate::Empty;
+        }
+        for (point, coordinate) in moved {
+            self.coordinates[point] = coordinate;
+        }
+        // The robot is always the first thing pushed.
+        self.robot = pushed[0].1;
+    }
+
+    fn double(self) -> Self {
+        let mut doubled_row = Vec::new();
+        for row in self.coordinates.rows() {
+            for coordinate in row.iter() {
+                match coordinate {
+                    Coordinate::Obstruction => {
+                        doubled_row.push(Coordinate::Obstruction);
+                        doubled_row.push(Coordinate::Obstruction);
+                    }
+                    Coordinate::Robot => {
+                        doubled_row.push(Coordinate::Robot);
+                        doubled_row.push(Coordinate::Empty);
+                    }
+                    Coordinate::Empty => {
+                        doubled_row.push(Coordinate::Empty);
+                        doubled_row.push(Coordinate::Empty);
+                    }
+                    Coordinate::Box => {
+                        doubled_row.push(Coordinate::LeftBox);
+                        doubled_row.push(Coordinate::RightBox);
+                    }
+                    _ => panic!("Already doubled!"),
+                }
+            }
+        }
+
+        let doubled_coordinates = Grid::new(
+            self.coordinates.width() * 2,
+            self.coordinates.height(),
+            doubled_row,
+        );
+        let doubled_robot = Point::new(self.robot.x * 2, self.robot.y);
+        Warehouse::new(doubled_coordinates, doubled_robot)
+    }
+
+    fn frame(&self) -> Image {
+        Image::from_grid(&self.coordinates, |coordinate| {
+            coordinate.colour().unwrap_or(Colour::BLACK)
+        })
+        .scaled(8)
+    }
+}
+
+impl fmt::Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.coordinates)
+    }
+}
+
+type Directions = Vec<Direction>;
+
+fn parse_input(input: &str) -> Result<(Warehouse, Directions), ParseError> {
+    let sections = sections(input);
+    let (grid_lines, command_lines) = match &sections[..] {
+        [] => return Err(ParseError::new("empty input").expected("a warehouse map")),
+        [grid] => (grid, &[][..]),
+        [grid, commands] => (grid, &commands[..]),
+        [_, _, extra, ..] => {
+            return Err(extra[0]
+                .error("unexpected section")
+                .expected("the end of the input"))
+        }
+    };
+
+    // The grid is parsed on its own, so its line numbers need shifting to
+    // where it starts in the input.
+    let line_offset = grid_lines[0].number - 1;
+    let raw_grid: Vec<&str> = grid_lines.iter().map(|line| line.text).collect();
+    let (coordinates, markers) =
+        Grid::parse_with_markers(&raw_grid.join("\n"), &['@'], |c, _| match c {
+            '#' => Ok(Coordinate::Obstruction),
+            '@' => Ok(Coordinate::Robot),
+            'O' => Ok(Coordinate::Box),
+            '.' => Ok(Coordinate::Empty),
+            _ => Err("Unknown char in grid"),
+        })
+        .map_err(|error| {
+            let error = ParseError::from(error).expected("one of #@O.");
+            ParseError {
+                line: error.line.map(|line| line + line_offset),
+                ..error
+            }
+        })?;
+
+    let mut commands = Vec::new();
+    for line in command_lines {
+        for (index, c) in line.text.char_indices() {
+            let direction = match c {
+                '^' | '>' | 'v' | '<' => Direction::try_from(c).expect("arrows are directions"),
+                _ => {
+                    return Err(line
+                        .error_at(
+                            &line.text[index..index + c.len_utf8()],
+                            "Unknown char in instructions",
+                        )
+                        .expected("one of ^>v<"))
+                }
+            };
+            commands.push(direction);
+        }
+    }
+
+    let robot = match markers.get('@') {
+        [robot] => *robot,
+        [] => return Err(ParseError::new("No robot in input.").expected("an @")),
+        [_, second, ..] => {
+            return Err(ParseError {
+                column: Some(second.x + 1),
+                ..ParseError::at_line(second.y + 1 + line_offset, "Multiple robots in input.")
+            })
+        }
+    };
+
+    Ok((Warehouse::new(coordinates, robot), commands))
+}
+
+fn solution_1(mut grid: Warehouse, directions: Directions) -> u64 {
+    for direction in directions.iter() {
+        grid.move_robot(*direction);
+    }
+
+    let mut sum: u64 = 0;
+    for (point, coordinate) in grid.coordinates.iter() {
+        if *coordinate == Coordinate::Box {
+            sum += (point.y as u64 * 100) + point.x as u64;
+        }
+    }
+    sum
+}
+
+fn solution_2(mut grid: Warehouse, directions: Directions) -> u64 {
+    for direction in directions.iter() {
+        grid.move_robot(*direction);
+    }
+
+    let mut sum: u64 = 0;
+    for (point, coordinate) in grid.coordinates.iter() {
+        if *coordinate == Coordinate::LeftBox {
+            sum += (point.y as u64 * 100) + point.x as u64;
+        }
+    }
+    sum
+}
+
+// Animates every move in the wide warehouse into `out/warehouse.gif`, a
+// frame at a time.
+fn animate(mut grid: Warehouse, directions: Directions) {
+    create_dir_all("out").unwrap();
+    let first = grid.frame();
+    let moves = directions.iter().map(|direction| {
+        grid.move_robot(*direction);
+        grid.frame()
+    });
+    save_gif(iter::once(first).chain(moves), 5, "out/warehouse.gif").unwrap();
+    println!("{}", grid);
+}
+
+const EXAMPLE_1: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+const EXAMPLE_2: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+const EXAMPLE_3: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+";
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = (Warehouse, Directions);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("small example", EXAMPLE_1).part1("2028"),
+        Example::new("larger example", EXAMPLE_2)
+            .part1("10092")
+            .part2("9021"),
+        Example::new("wide example", EXAMPLE_3).part2("618"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (grid, directions) = input;
+        solution_1(grid.clone(), directions.clone()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (grid, directions) = input;
+        solution_2(grid.clone().double(), directions.clone()).into()
+    }
+
+    fn visualise(input: &Self::Input) {
+        let (grid, directions) = input;
+        animate(grid.clone().double(), directions.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, solution_1, Day15, EXAMPLE_1};
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day15>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day15>();
+    }
+
+    #[test]
+    fn only_arrows_move_the_robot() {
+        let error = parse_input("#####\n#@O.#\n#####\n\nNNE>\n").err().unwrap();
+        assert_eq!((error.line, error.column), (Some(5), Some(1)));
+        assert_eq!(error.found.as_deref(), Some("N"));
+    }
+
+    #[test]
+    fn pushing_off_the_edge_of_an_unwalled_map() {
+        let (warehouse, directions) = parse_input("@O.\n...\n\n<^>>>>vvv\n").unwrap();
+        // The box stops at the right edge and the robot at the bottom one:
+        // neither push wraps or underflows.
+        assert_eq!(solution_1(warehouse, directions), 2);
+    }
+
+    #[test]
+    fn crlf_line_endings() {
+        let (warehouse, directions) = parse_input(&EXAMPLE_1.replace('\n', "\r\n")).unwrap();
+        assert_eq!(directions.len(), 15);
+        assert_eq!(solution_1(warehouse, directions), 2028);
+    }
+}
@@ -0,0 +1,273 @@
+use aoc_utils::render::{CellGlyph, Colour};
+use aoc_utils::search::{bfs, dijkstra, dijkstra_all, SearchResult};
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
+use aoc_utils::{Direction, Grid, Point, Turn};
+use std::collections::BTreeSet;
+use std::io::{self, IsTerminal};
+
+// The reindeer's position and the way it's facing.
+type State = (Point, Direction);
+
+const STEP_SCORE: u64 = 1;
+const TURN_SCORE: u64 = 1000;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Coordinate {
+    Obstruction,
+    Start,
+    End,
+    Empty,
+}
+
+impl CellGlyph for Coordinate {
+    fn glyph(&self) -> char {
+        match self {
+            Coordinate::Obstruction => '#',
+            Coordinate::Empty => '.',
+            Coordinate::End => 'E',
+            Coordinate::Start => 'S',
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Grid<Coordinate>, Point), ParseError> {
+    let (grid, markers) = Grid::parse_with_markers(input, &['S', 'E'], |c, _| match c {
+        '#' => Ok(Coordinate::Obstruction),
+        '.' => Ok(Coordinate::Empty),
+        'E' => Ok(Coordinate::End),
+        'S' => Ok(Coordinate::Start),
+        _ => Err(format!("Unkown char {:?} in grid", c)),
+    })?;
+
+    let start = markers
+        .first('S')
+        .ok_or_else(|| ParseError::new("no start in grid"))?;
+    let Some(end) = markers.first('E') else {
+        return Err(ParseError::new("no end in grid").expected("an end tile 'E'"));
+    };
+
+    // Checked here so the searches in both parts can count on finding a path.
+    let open = |point: &Point| {
+        grid.neighbours4(*point)
+            .filter(|(_, _, coordinate)| **coordinate != Coordinate::Obstruction)
+            .map(|(_, neighbour, _)| neighbour)
+            .collect::<Vec<_>>()
+    };
+    if bfs(start, open, |point| *point == end).is_none() {
+        return Err(ParseError::new("no path from the start to the end"));
+    }
+    Ok((grid, start))
+}
+
+fn count_path_values(path: &[State]) -> u64 {
+    let mut sum: u64 = 0;
+    let mut path_iter = path.iter();
+    let (mut last_point, mut last_direction) = path_iter.next().unwrap();
+    for (point, direction) in path_iter {
+        if *direction != last_direction {
+            sum += TURN_SCORE;
+        }
+        if *point != last_point {
+            sum += STEP_SCORE;
+        }
+        (last_point, last_direction) = (*point, *direction);
+    }
+    sum
+}
+
+// Turning on the spot, or a step forward if there's no wall in the way.
+fn moves(grid: &Grid<Coordinate>, (point, direction): &State) -> Vec<(State, u64)> {
+    let mut moves = vec![
+        ((*point, direction.turn_left()), TURN_SCORE),
+        ((*point, direction.turn_right()), TURN_SCORE),
+    ];
+
+    if let Some(ahead) = point.checked_step(*direction) {
+        if grid
+            .get(ahead)
+            .is_some_and(|c| *c != Coordinate::Obstruction)
+        {
+            moves.push(((ahead, *direction), STEP_SCORE));
+        }
+    }
+    moves
+}
+
+fn is_end(grid: &Grid<Coordinate>, (point, _): &State) -> bool {
+    grid[*point] == Coordinate::End
+}
+
+fn lowest_score_path(grid: &Grid<Coordinate>, start: Point) -> Option<SearchResult<State, u64>> {
+    dijkstra(
+        (start, Direction::Right),
+        |state| moves(grid, state),
+        |state| is_end(grid, state),
+    )
+}
+
+// Everything lying on at least one of the lowest scoring paths.
+struct BestPaths {
+    score: u64,
+    states: BTreeSet<State>,
+    tiles: BTreeSet<Point>,
+    count: u64,
+}
+
+fn best_paths(grid: &Grid<Coordinate>, start: Point) -> Option<BestPaths> {
+    let best = dijkstra_all(
+        (start, Direction::Right),
+        |state| moves(grid, state),
+        |state| is_end(grid, state),
+    )?;
+    let predecessors = best.predecessors?;
+
+    let states: BTreeSet<State> = predecessors
+        .states_on_paths(&best.goals)
+        .into_iter()
+        .collect();
+    let tiles = states.iter().map(|(point, _)| *point).collect();
+
+    Some(BestPaths {
+        score: best.cost,
+        tiles,
+        count: predecessors.count_paths(&best.goals),
+        states,
+    })
+}
+
+fn solution_1(grid: &Grid<Coordinate>, start: Point) -> u64 {
+    lowest_score_path(grid, start)
+        .expect("parse checked there is a path")
+        .cost
+}
+
+fn solution_2(grid: &Grid<Coordinate>, start: Point) -> u64 {
+    let best = best_paths(grid, start).expect("parse checked there is a path");
+    best.tiles.len() as u64
+}
+
+fn visualise(grid: &Grid<Coordinate>, start: Point) {
+    let colour = io::stdout().is_terminal();
+    let lowest = lowest_score_path(grid, start).expect("parse checked there is a path");
+    println!(
+        "SCORE {:?}, \n{}",
+        count_path_values(&lowest.path),
+        grid.render()
+            .path(lowest.path.iter().copied(), Colour::YELLOW)
+            .colour(colour)
+    );
+
+    let best = best_paths(grid, start).expect("parse checked there is a path");
+    println!(
+        "SCORE {:?}, PATHS {:?}, \n{}",
+        best.score,
+        best.count,
+        grid.render()
+            .path(best.states, Colour::YELLOW)
+            .colour(colour)
+    );
+}
+
+const EXAMPLE_1: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+const EXAMPLE_2: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = (Grid<Coordinate>, Point);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("example 1", EXAMPLE_1)
+            .part1("7036")
+            .part2("45"),
+        Example::new("example 2", EXAMPLE_2)
+            .part1("11048")
+            .part2("64"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (grid, start) = input;
+        solution_1(grid, *start).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (grid, start) = input;
+        solution_2(grid, *start).into()
+    }
+
+    fn visualise(input: &Self::Input) {
+        let (grid, start) = input;
+        visualise(grid, *start);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, Day16};
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day16>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day16>();
+    }
+
+    #[test]
+    fn maze_without_an_end() {
+        let error = parse_input("#####\n#S..#\n#####\n").unwrap_err();
+        assert_eq!(error.message, "no end in grid");
+        assert_eq!(error.expected.as_deref(), Some("an end tile 'E'"));
+    }
+
+    #[test]
+    fn maze_with_no_way_through() {
+        let error = parse_input("#####\n#S#E#\n#####\n").unwrap_err();
+        assert_eq!(error.message, "no path from the start to the end");
+    }
+}
@@ -1,7 +1,6 @@
-use regex::Regex;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::time::Instant;
+use aoc_utils::parsing::{sections, Pattern};
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
 
 #[derive(Debug, Clone, Copy)]
 struct Register {
@@ -28,13 +27,13 @@ impl Operand {
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-struct Instruction {
+pub struct Instruction {
     opcode: u8,
     operand: Operand,
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Registers {
+pub struct Registers {
     a: Register,
     b: Register,
     c: Register,
@@ -69,43 +68,55 @@ fn to_instructions(raw_instructions: Vec<u8>) -> Vec<Instruction> {
         .collect()
 }
 
-fn load_input(path: &str) -> (Registers, Vec<Instruction>) {
-    let mut instructions: Vec<Instruction> = Vec::new();
+const REGISTER: Pattern = Pattern::new("Register {}: {}");
+const PROGRAM: Pattern = Pattern::new("Program: {}");
+
+fn parse_input(input: &str) -> Result<(Registers, Vec<Instruction>), ParseError> {
     let mut initial_a: u64 = 0;
     let mut initial_b: u64 = 0;
     let mut initial_c: u64 = 0;
 
-    let register_re = Regex::new(r"Register [A-C]: (\d+)").unwrap();
-    let program_re = Regex::new(r"Program: (.+)").unwrap();
-    let file = File::open(path).expect("file not found!");
-    let reader = BufReader::new(file);
-    for line in reader.lines() {
-        let line = line.unwrap();
-        if let Some(caps) = register_re.captures(&line) {
-            let value: u64 = caps[1].parse().unwrap();
-            if line.contains("Register A") {
-                initial_a = value;
-            } else if line.contains("Register B") {
-                initial_b = value;
-            } else if line.contains("Register C") {
-                initial_c = value;
+    let [registers, program] = &sections(input)[..] else {
+        return Err(ParseError::new("wrong number of sections")
+            .expected("the registers and the program, separated by a blank line"));
+    };
+    for line in registers {
+        let register = REGISTER.matches(*line)?;
+        let value: u64 = register.parse(1, "a register value")?;
+        match register.get(0) {
+            "A" => initial_a = value,
+            "B" => initial_b = value,
+            "C" => initial_c = value,
+            name => {
+                return Err(line
+                    .error_at(name, "unknown register")
+                    .expected("A, B or C"))
             }
-        } else if let Some(caps) = program_re.captures(&line) {
-            instructions = to_instructions(
-                caps[1]
-                    .split(',')
-                    .collect::<Vec<&str>>()
-                    .iter()
-                    .map(|parsed| parsed.parse::<u8>().unwrap())
-                    .collect(),
-            );
         }
     }
 
-    (
+    let [line] = program[..] else {
+        return Err(program[1]
+            .error("more than one program")
+            .expected("a single line"));
+    };
+    let program = PROGRAM.matches(line)?;
+    let raw_instructions: Vec<u8> = program.split(0, ",", "a 3-bit number")?;
+    for (token, value) in program.get(0).split(',').zip(&raw_instructions) {
+        if *value >= 8 {
+            return Err(line
+                .error_at(token, "invalid program")
+                .expected("a 3-bit number"));
+        }
+    }
+    if !raw_instructions.len().is_multiple_of(2) {
+        return Err(line.error("last opcode has no operand"));
+    }
+
+    Ok((
         Registers::new(initial_a, initial_b, initial_c),
-        instructions,
-    )
+        to_instructions(raw_instructions),
+    ))
 }
 struct Executor<'a> {
     instruction_pointer: usize,
@@ -127,7 +138,11 @@ impl<'a> Executor<'a> {
             0 => self.adv(instruction.operand),
             1 => self.bxl(instruction.operand),
             2 => self.bst(instruction.operand),
-            3 => self.jnz(instruction.operand),
+            3 => {
+                // Jumps move the pointer themselves.
+                self.jnz(instruction.operand);
+                return;
+            }
             4 => self.bxc(instruction.operand),
             5 => self.out(instruction.operand),
             6 => self.bdv(instruction.operand),
@@ -151,7 +166,9 @@ impl<'a> Executor<'a> {
 
     fn jnz(&mut self, operand: Operand) {
         if self.registers.a.value != 0 {
-            self.instruction_pointer = (operand.literal_value / 2) as usize - 1;
+            self.instruction_pointer = (operand.literal_value / 2) as usize;
+        } else {
+            self.instruction_pointer += 1;
         }
     }
 
@@ -204,16 +221,13 @@ fn find_output_matches_instructions(instructions: Vec<Instruction>) -> u64 {
 
     loop {
         initial_a += 1;
-        if initial_a % 100000000 == 0 {
-            println!("Trying a: {}", initial_a);
-        }
         let mut registers = Registers::new(initial_a, 0, 0);
         let mut executor = Executor::new(&mut registers);
         while executor.instruction_pointer < instructions.len() {
             executor.execute(instructions[executor.instruction_pointer]);
-            let last_output_index = executor.outputs.len() - 1;
-            if !executor.outputs.is_empty()
-                && raw_instructions[last_output_index] != executor.outputs[last_output_index]
+            let last_output = executor.outputs.last();
+            if last_output.is_some()
+                && raw_instructions.get(executor.outputs.len() - 1) != last_output
             {
                 break;
             }
@@ -228,25 +242,61 @@ fn solution_2(instructions: Vec<Instruction>) -> u64 {
     find_output_matches_instructions(instructions)
 }
 
-fn main() {
-    let input_start = Instant::now();
-    let (registers, instructions) = load_input("input.dat");
-    println!("input took {:?}", input_start.elapsed());
-    println!("{:?}, {:?}", registers, instructions);
-
-    let solution_1_start = Instant::now();
-    let output_1 = solution_1(registers.clone(), instructions.clone());
-    println!(
-        "solution_1: {:?}, took {:?}",
-        output_1,
-        solution_1_start.elapsed()
-    );
-    let solution_2_start = Instant::now();
-    let output_2 = solution_2(instructions.clone());
-
-    println!(
-        "solution_2: {:?}, took {:?}",
-        output_2,
-        solution_2_start.elapsed()
-    );
+const EXAMPLE_1: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+const EXAMPLE_2: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = (Registers, Vec<Instruction>);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("example 1", EXAMPLE_1).part1("4,6,3,5,6,3,5,2,1,0"),
+        Example::new("example 2", EXAMPLE_2).part2("117440"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (registers, instructions) = input;
+        solution_1(*registers, instructions.clone()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (_, instructions) = input;
+        solution_2(instructions.clone()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day17;
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day17>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day17>();
+    }
 }
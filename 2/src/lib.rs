@@ -0,0 +1,497 @@
+use aoc_utils::parsing::lines;
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
+use std::fmt;
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let row = line.words("a level")?;
+            if row.is_empty() {
+                return Err(line.error("empty report").expected("at least one level"));
+            }
+            Ok(row)
+        })
+        .collect()
+}
+
+/// Which way the levels in a safe report may go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Directions {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as the whole report goes the same way.
+    Either,
+}
+
+/// What makes a report safe. The default is the puzzle's: levels all going
+/// the same way, in steps of 1 to 3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyRules {
+    pub min_step: u32,
+    pub max_step: u32,
+    pub directions: Directions,
+    /// Whether neighbouring levels can be equal, whatever `min_step` says.
+    pub allow_equal: bool,
+}
+
+impl Default for SafetyRules {
+    fn default() -> SafetyRules {
+        SafetyRules {
+            min_step: 1,
+            max_step: 3,
+            directions: Directions::Either,
+            allow_equal: false,
+        }
+    }
+}
+
+impl SafetyRules {
+    fn step_violation(&self, previous: i32, next: i32, increasing: bool) -> Option<Violation> {
+        if previous == next {
+            return (!self.allow_equal).then_some(Violation::FlatStep);
+        }
+        let step = previous.abs_diff(next);
+        if (next > previous) != increasing {
+            Some(Violation::DirectionChange)
+        } else if step < self.min_step {
+            Some(Violation::StepTooSmall)
+        } else if step > self.max_step {
+            Some(Violation::StepTooLarge)
+        } else {
+            None
+        }
+    }
+
+    // The directions a safe report could be going in.
+    fn increasing(&self) -> &'static [bool] {
+        match self.directions {
+            Directions::Increasing => &[true],
+            Directions::Decreasing => &[false],
+            Directions::Either => &[true, false],
+        }
+    }
+}
+
+/// Why a pair of neighbouring levels makes a report unsafe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// The levels go the wrong way: against the allowed direction, or against
+    /// the report's first step if either is allowed.
+    DirectionChange,
+    /// The levels differ by less than the minimum step.
+    StepTooSmall,
+    /// The levels differ by more than the maximum step.
+    StepTooLarge,
+    /// The levels are equal, which isn't allowed.
+    FlatStep,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::DirectionChange => write!(f, "direction change"),
+            Violation::StepTooSmall => write!(f, "step too small"),
+            Violation::StepTooLarge => write!(f, "step too large"),
+            Violation::FlatStep => write!(f, "flat step"),
+        }
+    }
+}
+
+/// The first place a report breaks the rules: `index` is the level that broke
+/// them, compared with the one before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Failure {
+    pub index: usize,
+    pub violation: Violation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// Why the report is unsafe as it is, or `None` if it's safe.
+    pub failure: Option<Failure>,
+    /// The fewest levels to remove to make the report safe, if it can be done
+    /// within the allowed number of removals.
+    pub removals: Option<Vec<usize>>,
+}
+
+fn first_failure(row: &[i32], rules: &SafetyRules) -> Option<Failure> {
+    // If either direction is allowed, the first step that isn't flat picks one.
+    let mut increasing = match rules.increasing() {
+        [increasing] => Some(*increasing),
+        _ => None,
+    };
+    row.windows(2).enumerate().find_map(|(index, pair)| {
+        if pair[0] != pair[1] && increasing.is_none() {
+            increasing = Some(pair[1] > pair[0]);
+        }
+        rules
+            .step_violation(pair[0], pair[1], increasing.unwrap_or(true))
+            .map(|violation| Failure {
+                index: index + 1,
+                violation,
+            })
+    })
+}
+
+pub fn check_row(row: &[i32], rules: &SafetyRules) -> bool {
+    first_failure(row, rules).is_none()
+}
+
+// The fewest levels to remove, up to `max_removals`, to leave levels that only
+// go one way. Each kept level can only follow one of the `max_removals + 1`
+// before it, so this is a single pass keeping, for every level, the fewest
+// removals needed before it if it's kept and which level it follows.
+fn dampen_towards(
+    row: &[i32],
+    rules: &SafetyRules,
+    increasing: bool,
+    max_removals: usize,
+) -> Option<Vec<usize>> {
+    let mut best: Vec<Option<(usize, Option<usize>)>> = Vec::with_capacity(row.len());
+    for (index, level) in row.iter().enumerate() {
+        // Removing every level before this one.
+        let mut choice = (index <= max_removals).then_some((index, None));
+        for previous in index.saturating_sub(max_removals.saturating_add(1))..index {
+            let Some((removed, _)) = best[previous] else {
+                continue;
+            };
+            let removed = removed + index - previous - 1;
+            if removed <= max_removals
+                && rules
+                    .step_violation(row[previous], *level, increasing)
+                    .is_none()
+                && choice.is_none_or(|(fewest, _)| removed < fewest)
+            {
+                choice = Some((removed, Some(previous)));
+            }
+        }
+        best.push(choice);
+    }
+
+    // Removing every level after the last one kept.
+    let (mut last, _) = (row.len().saturating_sub(max_removals.saturating_add(1))..row.len())
+        .filter_map(|index| {
+            best[index].map(|(removed, _)| (index, removed + row.len() - 1 - index))
+        })
+        .filter(|(_, removed)| *removed <= max_removals)
+        .min_by_key(|(_, removed)| *removed)?;
+
+    let mut kept = vec![false; row.len()];
+    loop {
+        kept[last] = true;
+        match best[last] {
+            Some((_, Some(previous))) => last = previous,
+            _ => break,
+        }
+    }
+    Some((0..row.len()).filter(|index| !kept[*index]).collect())
+}
+
+/// The fewest levels to remove, no more than `max_removals`, to make the
+/// report safe.
+fn dampen(row: &[i32], rules: &SafetyRules, max_removals: usize) -> Option<Vec<usize>> {
+    if row.is_empty() {
+        return Some(Vec::new());
+    }
+    rules
+        .increasing()
+        .iter()
+        .filter_map(|increasing| dampen_towards(row, rules, *increasing, max_removals))
+        .min_by_key(|removals| removals.len())
+}
+
+pub fn check_row_lenient(row: &[i32], rules: &SafetyRules, max_removals: usize) -> bool {
+    dampen(row, rules, max_removals).is_some()
+}
+
+pub fn diagnose(row: &[i32], rules: &SafetyRules, max_removals: usize) -> Diagnosis {
+    Diagnosis {
+        failure: first_failure(row, rules),
+        removals: dampen(row, rules, max_removals),
+    }
+}
+
+fn solution_1(input_data: &[Vec<i32>]) -> usize {
+    let rules = SafetyRules::default();
+    input_data
+        .iter()
+        .filter(|row| check_row(row, &rules))
+        .count()
+}
+
+fn solution_2(input_data: &[Vec<i32>]) -> usize {
+    let rules = SafetyRules::default();
+    input_data
+        .iter()
+        .filter(|row| check_row_lenient(row, &rules, 1))
+        .count()
+}
+
+fn visualise(input_data: &[Vec<i32>]) {
+    for (index, row) in input_data.iter().enumerate() {
+        let Diagnosis {
+            failure: Some(failure),
+            removals,
+        } = diagnose(row, &SafetyRules::default(), 1)
+        else {
+            continue;
+        };
+        let report = row
+            .iter()
+            .map(|level| level.to_string())
+            .collect::<Vec<_>>();
+        print!(
+            "report {} ({}): {} at index {}",
+            index + 1,
+            report.join(" "),
+            failure.violation,
+            failure.index
+        );
+        match removals {
+            Some(removals) => println!(", safe without indices {:?}", removals),
+            None => println!(", can't be made safe"),
+        }
+    }
+}
+
+const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i32>>;
+
+    const EXAMPLES: &'static [Example] = &[Example::new("example", EXAMPLE).part1("2").part2("4")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solution_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solution_2(input).into()
+    }
+
+    /// Lists every unsafe report, why it's unsafe and how to dampen it.
+    fn visualise(input: &Self::Input) {
+        visualise(input);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        check_row, check_row_lenient, dampen, diagnose, first_failure, Day2, Directions, Failure,
+        SafetyRules, Violation,
+    };
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day2>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day2>();
+    }
+
+    fn failure(index: usize, violation: Violation) -> Option<Failure> {
+        Some(Failure { index, violation })
+    }
+
+    #[test]
+    fn diagnoses_why_reports_fail() {
+        let rules = SafetyRules::default();
+        assert_eq!(first_failure(&[7, 6, 4, 2, 1], &rules), None);
+        assert_eq!(
+            first_failure(&[1, 2, 7, 8, 9], &rules),
+            failure(2, Violation::StepTooLarge)
+        );
+        assert_eq!(
+            first_failure(&[1, 3, 2, 4, 5], &rules),
+            failure(2, Violation::DirectionChange)
+        );
+        assert_eq!(
+            first_failure(&[8, 6, 4, 4, 1], &rules),
+            failure(3, Violation::FlatStep)
+        );
+
+        assert_eq!(diagnose(&[1, 2, 7, 8, 9], &rules, 1).removals, None);
+        assert_eq!(
+            diagnose(&[1, 2, 7, 8, 9], &rules, 2).removals,
+            Some(vec![0, 1])
+        );
+        // Either of the 4s can go.
+        assert!(matches!(
+            diagnose(&[8, 6, 4, 4, 1], &rules, 1).removals.as_deref(),
+            Some([2] | [3])
+        ));
+    }
+
+    #[test]
+    fn min_step() {
+        let rules = SafetyRules {
+            min_step: 2,
+            ..SafetyRules::default()
+        };
+        assert!(check_row(&[1, 3, 6], &rules));
+        assert_eq!(
+            first_failure(&[1, 3, 4], &rules),
+            failure(2, Violation::StepTooSmall)
+        );
+        assert!(check_row(&[1, 3, 4], &SafetyRules::default()));
+    }
+
+    #[test]
+    fn max_step() {
+        let rules = SafetyRules {
+            max_step: 5,
+            ..SafetyRules::default()
+        };
+        assert!(check_row(&[1, 6, 8], &rules));
+        assert_eq!(
+            first_failure(&[1, 6, 12], &rules),
+            failure(2, Violation::StepTooLarge)
+        );
+        assert!(!check_row(&[1, 6, 8], &SafetyRules::default()));
+    }
+
+    #[test]
+    fn directions() {
+        let going = |directions| SafetyRules {
+            directions,
+            ..SafetyRules::default()
+        };
+        assert!(check_row(&[1, 2, 3], &going(Directions::Increasing)));
+        assert_eq!(
+            first_failure(&[3, 2, 1], &going(Directions::Increasing)),
+            failure(1, Violation::DirectionChange)
+        );
+        assert!(check_row(&[3, 2, 1], &going(Directions::Decreasing)));
+        assert_eq!(
+            first_failure(&[1, 2, 3], &going(Directions::Decreasing)),
+            failure(1, Violation::DirectionChange)
+        );
+        assert!(check_row(&[1, 2, 3], &going(Directions::Either)));
+        assert!(check_row(&[3, 2, 1], &going(Directions::Either)));
+        assert_eq!(
+            first_failure(&[1, 2, 1], &going(Directions::Either)),
+            failure(2, Violation::DirectionChange)
+        );
+        // Only removing the 3 makes it go up.
+        assert_eq!(
+            dampen(&[3, 1, 2], &going(Directions::Increasing), 1),
+            Some(vec![0])
+        );
+    }
+
+    #[test]
+    fn allow_equal() {
+        let rules = SafetyRules {
+            allow_equal: true,
+            ..SafetyRules::default()
+        };
+        assert!(check_row(&[1, 1, 2], &rules));
+        // A flat start doesn't pick a direction.
+        assert!(check_row(&[2, 2, 1], &rules));
+        assert!(check_row(&[4, 4, 4], &rules));
+        assert_eq!(
+            first_failure(&[1, 1, 2], &SafetyRules::default()),
+            failure(1, Violation::FlatStep)
+        );
+    }
+
+    // Tries every way of removing up to `max_removals` levels.
+    fn fewest_removals(row: &[i32], rules: &SafetyRules, max_removals: usize) -> Option<usize> {
+        (0u32..1 << row.len())
+            .filter(|removed| removed.count_ones() as usize <= max_removals)
+            .filter(|removed| {
+                let kept: Vec<i32> = (0..row.len())
+                    .filter(|index| removed & (1 << index) == 0)
+                    .map(|index| row[index])
+                    .collect();
+                check_row(&kept, rules)
+            })
+            .map(|removed| removed.count_ones() as usize)
+            .min()
+    }
+
+    #[test]
+    fn dampener_removes_as_few_levels_as_brute_force() {
+        let all_rules = [
+            SafetyRules::default(),
+            SafetyRules {
+                min_step: 2,
+                max_step: 4,
+                ..SafetyRules::default()
+            },
+            SafetyRules {
+                directions: Directions::Decreasing,
+                ..SafetyRules::default()
+            },
+            SafetyRules {
+                allow_equal: true,
+                ..SafetyRules::default()
+            },
+        ];
+        let mut seed: u32 = 1;
+        let mut random = |bound: u32| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 16) % bound
+        };
+        for _ in 0..1000 {
+            let row: Vec<i32> = (0..1 + random(8)).map(|_| random(9) as i32).collect();
+            for (rules, max_removals) in all_rules
+                .iter()
+                .flat_map(|rules| (0..4).map(move |k| (rules, k)))
+            {
+                let removals = dampen(&row, rules, max_removals);
+                assert_eq!(
+                    removals.as_ref().map(Vec::len),
+                    fewest_removals(&row, rules, max_removals),
+                    "{:?} removing up to {} with {:?}",
+                    row,
+                    max_removals,
+                    rules
+                );
+                if let Some(removals) = removals {
+                    let kept: Vec<i32> = (0..row.len())
+                        .filter(|index| !removals.contains(index))
+                        .map(|index| row[index])
+                        .collect();
+                    assert!(
+                        check_row(&kept, rules),
+                        "{:?} without {:?} with {:?}",
+                        row,
+                        removals,
+                        rules
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unlimited_removals() {
+        let rules = SafetyRules::default();
+        assert!(check_row_lenient(&[1, 9, 2, 20, 3], &rules, usize::MAX));
+        assert_eq!(
+            dampen(&[1, 9, 2, 20, 3], &rules, usize::MAX),
+            Some(vec![1, 3])
+        );
+    }
+}
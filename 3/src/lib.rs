@@ -0,0 +1,441 @@
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
+use std::io::{self, Read};
+
+/// The puzzle's numbers are 1 to 3 digits, which also keeps how much of the
+/// input the lexer has to hold on to small.
+const MAX_DIGITS: usize = 3;
+
+/// What an instruction does when it runs.
+#[derive(Debug, Clone, Copy)]
+pub enum Action {
+    /// Adds what the function makes of the arguments to the total, unless
+    /// instructions are disabled.
+    Accumulate(fn(&[i64]) -> i64),
+    /// Runs the instructions after it.
+    Enable,
+    /// Skips the instructions after it, until the next `Enable`.
+    Disable,
+}
+
+/// An instruction `name(a,b,...)` taking `arity` numbers.
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    pub name: &'static str,
+    pub arity: usize,
+    pub action: Action,
+}
+
+pub const MUL: Instruction = Instruction {
+    name: "mul",
+    arity: 2,
+    action: Action::Accumulate(|args| args[0] * args[1]),
+};
+pub const ADD: Instruction = Instruction {
+    name: "add",
+    arity: 2,
+    action: Action::Accumulate(|args| args[0] + args[1]),
+};
+pub const SUB: Instruction = Instruction {
+    name: "sub",
+    arity: 2,
+    action: Action::Accumulate(|args| args[0] - args[1]),
+};
+pub const DO: Instruction = Instruction {
+    name: "do",
+    arity: 0,
+    action: Action::Enable,
+};
+pub const DONT: Instruction = Instruction {
+    name: "don't",
+    arity: 0,
+    action: Action::Disable,
+};
+
+/// Part 1 doesn't know about `do()` and `don't()` yet.
+pub const PART_1: &[Instruction] = &[MUL];
+pub const PART_2: &[Instruction] = &[MUL, DO, DONT];
+
+/// Why some text isn't an instruction (yet).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Incomplete {
+    /// More text could make it one.
+    Partial,
+    /// Nothing after it can.
+    Invalid,
+}
+
+// Strips `byte` off the front of `rest`.
+fn expect(rest: &[u8], byte: u8) -> Result<&[u8], Incomplete> {
+    match rest.split_first() {
+        None => Err(Incomplete::Partial),
+        Some((first, after)) if *first == byte => Ok(after),
+        Some(_) => Err(Incomplete::Invalid),
+    }
+}
+
+// The arguments, if `text` is exactly the instruction.
+fn arguments(instruction: &Instruction, text: &[u8]) -> Result<Vec<i64>, Incomplete> {
+    let name = instruction.name.as_bytes();
+    if text.len() <= name.len() {
+        return Err(match name.starts_with(text) {
+            true => Incomplete::Partial,
+            false => Incomplete::Invalid,
+        });
+    }
+    let rest = text.strip_prefix(name).ok_or(Incomplete::Invalid)?;
+    let mut rest = expect(rest, b'(')?;
+
+    let mut args = Vec::with_capacity(instruction.arity);
+    while args.len() < instruction.arity {
+        if !args.is_empty() {
+            rest = expect(rest, b',')?;
+        }
+        let digits = rest.iter().take_while(|byte| byte.is_ascii_digit()).count();
+        if digits > MAX_DIGITS {
+            return Err(Incomplete::Invalid);
+        }
+        if digits == rest.len() {
+            return Err(Incomplete::Partial);
+        }
+        if digits == 0 {
+            return Err(Incomplete::Invalid);
+        }
+        let arg = rest[..digits]
+            .iter()
+            .fold(0, |arg, digit| arg * 10 + (digit - b'0') as i64);
+        args.push(arg);
+        rest = &rest[digits..];
+    }
+
+    match expect(rest, b')')? {
+        [] => Ok(args),
+        _ => Err(Incomplete::Invalid),
+    }
+}
+
+/// An instruction the lexer found, starting `offset` bytes into the input.
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub offset: usize,
+    pub instruction: Instruction,
+    pub args: Vec<i64>,
+}
+
+/// Finds instructions in corrupted memory a byte at a time, holding on to no
+/// more than the instruction it might be in the middle of.
+pub struct Lexer<'a> {
+    instructions: &'a [Instruction],
+    pending: Vec<u8>,
+    // The offset of the first pending byte, or of the next byte if there
+    // aren't any.
+    pending_start: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(instructions: &'a [Instruction]) -> Lexer<'a> {
+        Lexer {
+            instructions,
+            pending: Vec::new(),
+            pending_start: 0,
+        }
+    }
+
+    pub fn push(&mut self, byte: u8, emit: &mut impl FnMut(Token)) {
+        self.pending.push(byte);
+
+        let mut partial = false;
+        for instruction in self.instructions {
+            match arguments(instruction, &self.pending) {
+                Ok(args) => {
+                    emit(Token {
+                        offset: self.pending_start,
+                        instruction: *instruction,
+                        args,
+                    });
+                    self.pending_start += self.pending.len();
+                    self.pending.clear();
+                    return;
+                }
+                Err(Incomplete::Partial) => partial = true,
+                Err(Incomplete::Invalid) => {}
+            }
+        }
+
+        if !partial {
+            // Nothing starts at the first pending byte, but something might
+            // start at the ones after it.
+            let rest = self.pending.split_off(1);
+            self.pending.clear();
+            self.pending_start += 1;
+            for byte in rest {
+                self.push(byte, emit);
+            }
+        }
+    }
+}
+
+/// Whether an instruction ran, as the interpreter's trace records it.
+#[derive(Debug, Clone)]
+pub struct Step {
+    pub token: Token,
+    /// False if it was skipped because instructions were disabled.
+    pub ran: bool,
+}
+
+pub struct Interpreter {
+    enabled: bool,
+    total: i64,
+    trace: Option<Vec<Step>>,
+}
+
+impl Default for Interpreter {
+    fn default() -> Interpreter {
+        Interpreter {
+            enabled: true,
+            total: 0,
+            trace: None,
+        }
+    }
+}
+
+impl Interpreter {
+    /// Also records every instruction in `trace`.
+    pub fn traced() -> Interpreter {
+        Interpreter {
+            trace: Some(Vec::new()),
+            ..Interpreter::default()
+        }
+    }
+
+    pub fn execute(&mut self, token: Token) {
+        // Enabling and disabling always run, the rest only when enabled.
+        let ran = match token.instruction.action {
+            Action::Accumulate(accumulate) => {
+                if self.enabled {
+                    self.total += accumulate(&token.args);
+                }
+                self.enabled
+            }
+            Action::Enable => {
+                self.enabled = true;
+                true
+            }
+            Action::Disable => {
+                self.enabled = false;
+                true
+            }
+        };
+        if let Some(trace) = &mut self.trace {
+            trace.push(Step { token, ran });
+        }
+    }
+
+    pub fn total(&self) -> i64 {
+        self.total
+    }
+
+    pub fn trace(&self) -> &[Step] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+}
+
+/// How much of the input is read at a time. The lexer carries any
+/// instruction split between chunks over to the next one, so only this and
+/// the few bytes it's holding on to are ever in memory.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Runs the instructions in `reader` through the interpreter, reading
+/// `chunk_size` bytes at a time. A `chunk_size` of zero is an
+/// `InvalidInput` error, as a read into an empty buffer looks like the end of
+/// the input.
+pub fn run(
+    instructions: &[Instruction],
+    mut reader: impl Read,
+    chunk_size: usize,
+    interpreter: &mut Interpreter,
+) -> io::Result<()> {
+    if chunk_size == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "chunk size must be at least one byte",
+        ));
+    }
+    let mut lexer = Lexer::new(instructions);
+    let mut chunk = vec![0; chunk_size];
+    loop {
+        let read = match reader.read(&mut chunk) {
+            Ok(0) => return Ok(()),
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        for byte in &chunk[..read] {
+            lexer.push(*byte, &mut |token| interpreter.execute(token));
+        }
+    }
+}
+
+pub fn solution_1(reader: impl Read) -> io::Result<i64> {
+    let mut interpreter = Interpreter::default();
+    run(PART_1, reader, CHUNK_SIZE, &mut interpreter)?;
+    Ok(interpreter.total())
+}
+
+pub fn solution_2(reader: impl Read) -> io::Result<i64> {
+    let mut interpreter = Interpreter::default();
+    run(PART_2, reader, CHUNK_SIZE, &mut interpreter)?;
+    Ok(interpreter.total())
+}
+
+fn visualise(input: &str) {
+    let mut interpreter = Interpreter::traced();
+    run(PART_2, input.as_bytes(), CHUNK_SIZE, &mut interpreter).expect("reading a str can't fail");
+    for step in interpreter.trace() {
+        let args: Vec<String> = step.token.args.iter().map(|arg| arg.to_string()).collect();
+        println!(
+            "{:>8}: {}({}) {}",
+            step.token.offset,
+            step.token.instruction.name,
+            args.join(","),
+            match step.ran {
+                true => "ran",
+                false => "disabled",
+            }
+        );
+    }
+    println!("total {}", interpreter.total());
+}
+
+const EXAMPLE_1: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+const EXAMPLE_2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = String;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("example 1", EXAMPLE_1).part1("161"),
+        Example::new("example 2", EXAMPLE_2).part2("48"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solution_1(input.as_bytes())
+            .expect("reading a str can't fail")
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solution_2(input.as_bytes())
+            .expect("reading a str can't fail")
+            .into()
+    }
+
+    /// Traces which instructions ran and which were disabled.
+    fn visualise(input: &Self::Input) {
+        visualise(input);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, solution_2, Day3, Interpreter, ADD, DO, DONT, MUL, PART_1, PART_2, SUB};
+    use aoc_utils::testing::{check_answers, check_examples};
+    use std::io;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day3>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day3>();
+    }
+
+    fn total(instructions: &[super::Instruction], input: &str) -> i64 {
+        let mut interpreter = Interpreter::default();
+        run(instructions, input.as_bytes(), 16, &mut interpreter).unwrap();
+        interpreter.total()
+    }
+
+    #[test]
+    fn lexes_corrupted_memory() {
+        // An instruction can start inside a broken one.
+        assert_eq!(total(PART_1, "mumul(2,3)mul(mul(4,5)"), 26);
+        // Numbers are 1 to 3 digits.
+        assert_eq!(total(PART_1, "mul(1234,5)mul(,5)mul(999,999)"), 998001);
+        assert_eq!(total(PART_1, "mul(2,3"), 0);
+    }
+
+    #[test]
+    fn runs_other_instructions() {
+        let instructions = [MUL, ADD, SUB, DO, DONT];
+        assert_eq!(
+            total(
+                &instructions,
+                "add(1,2)sub(3,10)don't()add(100,100)do()mul(2,5)"
+            ),
+            6
+        );
+    }
+
+    #[test]
+    fn traces_disabled_instructions() {
+        let mut interpreter = Interpreter::traced();
+        // Small chunks, so the trace offsets have to carry across them.
+        run(
+            &[MUL, DO, DONT],
+            b"mul(1,2)don't()mul(3,4)do()".as_slice(),
+            5,
+            &mut interpreter,
+        )
+        .unwrap();
+        let trace: Vec<(usize, &str, bool)> = interpreter
+            .trace()
+            .iter()
+            .map(|step| (step.token.offset, step.token.instruction.name, step.ran))
+            .collect();
+        assert_eq!(
+            trace,
+            [
+                (0, "mul", true),
+                (8, "don't", true),
+                (15, "mul", false),
+                (23, "do", true)
+            ]
+        );
+        assert_eq!(interpreter.total(), 2);
+    }
+
+    #[test]
+    fn instructions_split_across_chunks() {
+        let memory = b"xmul(2,4)don't()_mul(5,5)+do()mul(11,8)".repeat(1000);
+        let expected = solution_2(memory.as_slice()).unwrap();
+        assert_eq!(expected, 96 * 1000);
+        for chunk_size in [1, 2, 3, 7, 4096] {
+            let mut interpreter = Interpreter::default();
+            run(PART_2, memory.as_slice(), chunk_size, &mut interpreter).unwrap();
+            assert_eq!(interpreter.total(), expected, "chunks of {}", chunk_size);
+        }
+    }
+
+    #[test]
+    fn zero_chunk_size() {
+        let mut interpreter = Interpreter::default();
+        let error = run(PART_1, &b"mul(2,4)"[..], 0, &mut interpreter).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,175 @@
+use aoc_utils::stencil::Stencil;
+use aoc_utils::testing::Example;
+use aoc_utils::word_search::WordSearch;
+use aoc_utils::{Answer, Grid, ParseError, Point, Solution};
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::io::{self, IsTerminal};
+
+type Letters = Grid<char>;
+
+fn parse_input(input: &str) -> Result<Letters, ParseError> {
+    let letters = Grid::parse(input, |letter, _| Ok::<_, Infallible>(letter))?;
+    Ok(letters)
+}
+
+/// The points of each XMAS found, letter by letter.
+fn xmas_matches(input: &Letters) -> Vec<Vec<Point>> {
+    WordSearch::new(["XMAS"])
+        .find(input)
+        .into_iter()
+        .map(|found| found.points)
+        .collect()
+}
+
+/// The two MASes crossing, each of which can be written either way round.
+const X_MAS: &str = "M.S/.A./M.S";
+
+/// The points of the five letters of each X-MAS found.
+fn x_mas_matches(input: &Letters) -> Vec<Vec<Point>> {
+    Stencil::parse(X_MAS)
+        .expect("X_MAS is a valid stencil")
+        .find(input)
+        .into_iter()
+        .map(|placement| placement.points)
+        .collect()
+}
+
+fn solution_1(input: &Letters) -> usize {
+    xmas_matches(input).len()
+}
+
+fn solution_2(input: &Letters) -> usize {
+    x_mas_matches(input).len()
+}
+
+/// Draws the grid the way the puzzle illustrates it, keeping only the letters
+/// that are part of a match. With `colour` on, each match gets its own colour.
+fn render_matches(input: &Letters, matches: &[Vec<Point>], colour: bool) -> String {
+    let matched: HashSet<Point> = matches.iter().flatten().copied().collect();
+    let unmatched = input
+        .points()
+        .filter(|point| !matched.contains(point))
+        .map(|point| (point, '.'));
+    input
+        .render()
+        .glyphs(unmatched)
+        .regions(matches.iter().map(|points| points.iter().copied()))
+        .colour(colour)
+        .to_string()
+}
+
+fn visualise(input: &Letters) {
+    let colour = io::stdout().is_terminal();
+    let xmas = xmas_matches(input);
+    println!(
+        "{} XMAS\n{}\n",
+        xmas.len(),
+        render_matches(input, &xmas, colour)
+    );
+    let x_mas = x_mas_matches(input);
+    println!(
+        "{} X-MAS\n{}",
+        x_mas.len(),
+        render_matches(input, &x_mas, colour)
+    );
+}
+
+const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Letters;
+
+    const EXAMPLES: &'static [Example] = &[Example::new("example", EXAMPLE).part1("18").part2("9")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solution_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solution_2(input).into()
+    }
+
+    fn visualise(input: &Self::Input) {
+        visualise(input);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, render_matches, x_mas_matches, xmas_matches, Day4, EXAMPLE};
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day4>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day4>();
+    }
+
+    #[test]
+    fn rendered_like_the_puzzle() {
+        let letters = parse_input(EXAMPLE).unwrap();
+        let xmas = render_matches(&letters, &xmas_matches(&letters), false);
+        assert_eq!(
+            xmas,
+            "\
+....XXMAS.
+.SAMXMS...
+...S..A...
+..A.A.MS.X
+XMASAMX.MM
+X.....XA.A
+S.S.S.S.SS
+.A.A.A.A.A
+..M.M.M.MM
+.X.X.XMASX"
+        );
+
+        let x_mas = render_matches(&letters, &x_mas_matches(&letters), false);
+        assert_eq!(
+            x_mas,
+            "\
+.M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+.........."
+        );
+    }
+
+    #[test]
+    fn each_match_coloured() {
+        let letters = parse_input("XMAS\nXMAS\n").unwrap();
+        let rendered = render_matches(&letters, &xmas_matches(&letters), true);
+        assert_eq!(rendered.matches("\x1b[38;2;220;50;47m").count(), 4);
+        assert_eq!(rendered.matches("\x1b[38;2;64;160;43m").count(), 4);
+    }
+}
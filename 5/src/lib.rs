@@ -1,21 +1,27 @@
+use aoc_utils::parsing::{sections, Pattern};
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
 use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
-use std::time::Instant;
 
-fn load_input(path: &str) -> (HashMap<i32, HashSet<i32>>, Vec<Vec<i32>>) {
-    let file_string = read_to_string(path).expect("Failed to read file");
+type NumberRules = HashMap<i32, HashSet<i32>>;
 
-    let mut number_rules: HashMap<i32, HashSet<i32>> = HashMap::new();
+const RULE: Pattern = Pattern::new("{}|{}");
 
-    let (ordering_rules_raw, update_raw) = file_string.split_once("\n\n").expect("Bad file!");
-    for line in ordering_rules_raw.lines() {
-        let (page_before_raw, page_after_raw) = line.split_once("|").expect("Bad line!");
-        let page_before: i32 = page_before_raw.parse().unwrap();
-        let page_after: i32 = page_after_raw.parse().unwrap();
+fn parse_input(input: &str) -> Result<(NumberRules, Vec<Vec<i32>>), ParseError> {
+    let mut number_rules: NumberRules = HashMap::new();
+
+    let [ordering_rules_raw, update_raw] = &sections(input)[..] else {
+        return Err(ParseError::new("wrong number of sections")
+            .expected("the rules and the updates, separated by a blank line"));
+    };
+    for line in ordering_rules_raw {
+        let rule = RULE.matches(*line)?;
+        let page_before: i32 = rule.parse(0, "a page number")?;
+        let page_after: i32 = rule.parse(1, "a page number")?;
 
         number_rules
             .entry(page_before)
-            .or_insert(HashSet::new())
+            .or_default()
             .insert(page_after);
     }
 
@@ -24,21 +30,15 @@ fn load_input(path: &str) -> (HashMap<i32, HashSet<i32>>, Vec<Vec<i32>>) {
     // performance.
     let mut update_orders: Vec<Vec<i32>> = Vec::new();
 
-    for update_order_raw in update_raw.split('\n').filter(|x| !x.is_empty()) {
-        let update_order: Vec<i32> = update_order_raw
-            .split(",")
-            .filter_map(|x| x.parse::<i32>().ok())
-            .collect();
+    for line in update_raw {
+        let update_order: Vec<i32> = line.split(",", "a page number")?;
         update_orders.push(update_order);
     }
 
-    (number_rules, update_orders)
+    Ok((number_rules, update_orders))
 }
 
-fn check_correctly_ordered(
-    number_rules: &HashMap<i32, HashSet<i32>>,
-    update_order: &[i32],
-) -> bool {
+fn check_correctly_ordered(number_rules: &NumberRules, update_order: &[i32]) -> bool {
     for (order_index, page_number) in update_order.iter().enumerate() {
         if !(number_rules.contains_key(page_number)) {
             continue;
@@ -56,10 +56,7 @@ fn check_correctly_ordered(
     true
 }
 
-fn update_reordered(
-    number_rules: &HashMap<i32, HashSet<i32>>,
-    update_order: &Vec<i32>,
-) -> Option<Vec<i32>> {
+fn update_reordered(number_rules: &NumberRules, update_order: &[i32]) -> Option<Vec<i32>> {
     // Very sloppy while loop here
     // ... But apparently it's faster than getting all the indices which need to go after and then
     // swapping, despite this taking more iterations on average... :-/
@@ -92,7 +89,7 @@ fn update_reordered(
 
     let mut loop_ran_once = false;
 
-    let mut reordered_update: Vec<i32> = update_order.clone();
+    let mut reordered_update: Vec<i32> = update_order.to_vec();
 
     while !check_correctly_ordered(number_rules, &reordered_update) {
         loop_ran_once = true;
@@ -124,7 +121,7 @@ fn update_reordered(
     }
 }
 
-fn solution_1(number_rules: &HashMap<i32, HashSet<i32>>, update_orders: &[Vec<i32>]) -> i32 {
+fn solution_1(number_rules: &NumberRules, update_orders: &[Vec<i32>]) -> i32 {
     let mut sum = 0;
     for update_order in update_orders.iter() {
         if !check_correctly_ordered(number_rules, update_order) {
@@ -135,7 +132,7 @@ fn solution_1(number_rules: &HashMap<i32, HashSet<i32>>, update_orders: &[Vec<i3
     sum
 }
 
-fn solution_2(number_rules: &HashMap<i32, HashSet<i32>>, update_orders: &[Vec<i32>]) -> i32 {
+fn solution_2(number_rules: &NumberRules, update_orders: &[Vec<i32>]) -> i32 {
     let mut sum = 0;
     for update_order in update_orders.iter() {
         if let Some(reordered_update) = update_reordered(number_rules, update_order) {
@@ -145,24 +142,74 @@ fn solution_2(number_rules: &HashMap<i32, HashSet<i32>>, update_orders: &[Vec<i3
     sum
 }
 
-fn main() {
-    let input_start = Instant::now();
-    let (number_rules, update_order) = load_input("input.dat");
-    println!("input took {:?}", input_start.elapsed());
-
-    let solution_1_start = Instant::now();
-    let output_1 = solution_1(&number_rules, &update_order);
-    println!(
-        "solution_1: {:?}, took {:?}",
-        output_1,
-        solution_1_start.elapsed()
-    );
-
-    let solution_2_start = Instant::now();
-    let output_2 = solution_2(&number_rules, &update_order);
-    println!(
-        "solution_2: {:?}, took {:?}",
-        output_2,
-        solution_2_start.elapsed()
-    );
+const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = (NumberRules, Vec<Vec<i32>>);
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new("example", EXAMPLE).part1("143").part2("123")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (number_rules, update_orders) = input;
+        solution_1(number_rules, update_orders).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (number_rules, update_orders) = input;
+        solution_2(number_rules, update_orders).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day5;
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day5>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day5>();
+    }
 }
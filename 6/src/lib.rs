@@ -0,0 +1,191 @@
+use aoc_utils::image::{save_gif, Image};
+use aoc_utils::render::Colour;
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
+use aoc_utils::{Direction, Grid, Point, Turn};
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::fs::create_dir_all;
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum Coordinate {
+    Empty,
+    Obstruction,
+}
+
+type Coordinates = Grid<Coordinate>;
+type PathTaken = Vec<(Direction, Point)>;
+
+const GUARDS: [char; 4] = ['^', '<', 'v', '>'];
+
+fn parse_input(input: &str) -> Result<(Coordinates, PathTaken), ParseError> {
+    let (coordinates, guards) = Grid::parse_with_markers(input, &GUARDS, |raw_char, _| {
+        match raw_char {
+            '.' => Ok(Coordinate::Empty),
+            '#' => Ok(Coordinate::Obstruction),
+            // The guard is standing on empty floor.
+            _ if GUARDS.contains(&raw_char) => Ok(Coordinate::Empty),
+            _ => Err("invalid character in input"),
+        }
+    })
+    .map_err(|error| ParseError::from(error).expected("one of .#^<v>"))?;
+
+    let path_taken: PathTaken = guards
+        .iter()
+        .map(|(guard, point)| {
+            let direction = Direction::try_from(guard).expect("guards are arrows");
+            (direction, point)
+        })
+        .collect();
+    match path_taken[..] {
+        [_] => Ok((coordinates, path_taken)),
+        [] => Err(ParseError::new("no guard in the lab").expected("one of ^<v>")),
+        [_, (_, second), ..] => Err(ParseError {
+            column: Some(second.x + 1),
+            ..ParseError::at_line(second.y + 1, "more than one guard")
+        }),
+    }
+}
+
+fn walk_guard(coordinates: &Coordinates, path_taken: &mut PathTaken) -> bool {
+    loop {
+        // Returns true if the guard left the boundary, false if she entered a loop.
+        let (mut direction, current) = *path_taken.last().unwrap();
+
+        let mut next = match current.checked_step(direction) {
+            Some(next) if coordinates.get(next).is_some() => next,
+            _ => return true,
+        };
+
+        if path_taken.contains(&(direction, next)) {
+            return false;
+        }
+
+        if coordinates[next] == Coordinate::Obstruction {
+            // A turn counts as a move on the path, but we don't advance the guard in x, y.
+            (direction, next) = (direction.turn_right(), current);
+        }
+
+        path_taken.push((direction, next));
+    }
+}
+
+fn solution_1(coordinates: &Coordinates, path_taken: &mut PathTaken) -> i32 {
+    walk_guard(coordinates, path_taken);
+    let unique_elements: HashSet<_> = path_taken.iter().map(|(_, point)| point).collect();
+    unique_elements.len() as i32
+}
+
+fn check_obstruction(coordinates: &Coordinates, path_taken: &PathTaken) -> HashSet<Point> {
+    path_taken
+        .par_iter()
+        .enumerate()
+        .skip(2)
+        .filter_map(|(index, (_, obstruction))| {
+            let mut coordinates_copy = coordinates.clone();
+            let mut path_so_far: PathTaken = path_taken[0..index].to_vec();
+
+            if path_so_far.iter().any(|(_, point)| point == obstruction) {
+                return None;
+            }
+
+            coordinates_copy[*obstruction] = Coordinate::Obstruction;
+
+            if !walk_guard(&coordinates_copy, &mut path_so_far) {
+                return Some(*obstruction);
+            }
+            None
+        })
+        .collect()
+}
+
+fn solution_2(coordinates: &Coordinates, path_taken: &mut PathTaken) -> i32 {
+    walk_guard(coordinates, path_taken);
+    check_obstruction(coordinates, path_taken).len() as i32
+}
+
+// Animates the guard's walk into `out/guard.gif`, a frame per step, leaving a
+// trail behind her.
+fn animate(coordinates: &Coordinates, path_taken: &mut PathTaken) {
+    walk_guard(coordinates, path_taken);
+
+    let mut trail = Image::from_grid(coordinates, |coordinate| match coordinate {
+        Coordinate::Empty => Colour::BLACK,
+        Coordinate::Obstruction => Colour::GREY,
+    });
+    let frames = path_taken.iter().map(|(_, point)| {
+        trail.set(*point, Colour::RED);
+        let frame = trail.scaled(4);
+        trail.set(*point, Colour::YELLOW);
+        frame
+    });
+
+    create_dir_all("out").unwrap();
+    save_gif(frames, 2, "out/guard.gif").unwrap();
+    println!("{} steps written to out/guard.gif", path_taken.len());
+}
+
+const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = (Coordinates, PathTaken);
+
+    const EXAMPLES: &'static [Example] = &[Example::new("example", EXAMPLE).part1("41").part2("6")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (coordinates, path_taken) = input;
+        solution_1(coordinates, &mut path_taken.clone()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (coordinates, path_taken) = input;
+        solution_2(coordinates, &mut path_taken.clone()).into()
+    }
+
+    fn visualise(input: &Self::Input) {
+        let (coordinates, path_taken) = input;
+        animate(coordinates, &mut path_taken.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, Day6};
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day6>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day6>();
+    }
+
+    #[test]
+    fn second_guard_in_reading_order() {
+        let error = parse_input("^...\n....\n..>.\n.>..\n").err().unwrap();
+        assert_eq!((error.line, error.column), (Some(3), Some(3)));
+        assert_eq!(error.message, "more than one guard");
+    }
+}
@@ -1,27 +1,27 @@
+use aoc_utils::parsing::{lines, Pattern};
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
 use itertools::{repeat_n, Itertools};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::time::Instant;
 
-fn load_input(path: &str) -> Vec<(u64, Vec<u64>)> {
+const EQUATION: Pattern = Pattern::new("{}: {}");
+
+fn parse_input(input: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
     let mut equations: Vec<(u64, Vec<u64>)> = Vec::new();
 
-    let file = File::open(path).expect("failed to open file");
-    let reader = BufReader::new(file);
-    for line in reader.lines().filter_map(|line| line.ok()) {
-        let mut parts = line.splitn(2, ':');
-        let result_raw = parts.next().unwrap_or("");
-        let result: u64 = result_raw.parse().unwrap();
+    for line in lines(input) {
+        let fields = EQUATION.matches(line)?;
+        let result: u64 = fields.parse(0, "a test value")?;
 
-        let inputs_raw = parts.next().unwrap_or("");
-        let inputs: Vec<u64> = inputs_raw
-            .split_whitespace()
-            .map(|n| n.parse().unwrap())
-            .collect();
+        let inputs: Vec<u64> = fields.words(1, "a number")?;
+        if inputs.is_empty() {
+            return Err(line
+                .error("nothing to calibrate with")
+                .expected("at least one number after the colon"));
+        }
 
         equations.push((result, inputs));
     }
-    equations
+    Ok(equations)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -66,7 +66,7 @@ fn equation_could_be_true(equation: &(u64, &Vec<u64>), operations: &[Operation])
     false
 }
 
-fn solution_1(equations: &Vec<(u64, Vec<u64>)>) -> u64 {
+fn solution_1(equations: &[(u64, Vec<u64>)]) -> u64 {
     let mut total_calibration_result = 0;
     for (result, inputs) in equations.iter() {
         if equation_could_be_true(&(*result, inputs), &[Operation::Add, Operation::Multiply]) {
@@ -77,7 +77,7 @@ fn solution_1(equations: &Vec<(u64, Vec<u64>)>) -> u64 {
     total_calibration_result
 }
 
-fn solution_2(equations: &Vec<(u64, Vec<u64>)>) -> u64 {
+fn solution_2(equations: &[(u64, Vec<u64>)]) -> u64 {
     let mut total_calibration_result = 0;
     for (result, inputs) in equations.iter() {
         if equation_could_be_true(
@@ -91,23 +91,54 @@ fn solution_2(equations: &Vec<(u64, Vec<u64>)>) -> u64 {
     total_calibration_result
 }
 
-fn main() {
-    let input_start = Instant::now();
-    let equations = load_input("input.dat");
-    println!("input took {:?}", input_start.elapsed());
-
-    let solution_1_start = Instant::now();
-    let output_1 = solution_1(&equations);
-    println!(
-        "solution_1: {:?}, took {:?}",
-        output_1,
-        solution_1_start.elapsed()
-    );
-    let solution_2_start = Instant::now();
-    let output_2 = solution_2(&equations);
-    println!(
-        "solution_2: {:?}, took {:?}",
-        output_2,
-        solution_2_start.elapsed()
-    );
+const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    const EXAMPLES: &'static [Example] = &[Example::new("example", EXAMPLE)
+        .part1("3749")
+        .part2("11387")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solution_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solution_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day7;
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day7>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day7>();
+    }
 }
@@ -0,0 +1,193 @@
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
+use aoc_utils::{Grid, Point};
+use std::collections::HashSet;
+
+type Antenna = char;
+type AntennaGrid = Vec<Vec<Antenna>>;
+const EMPTY_ANTENNA: Antenna = '.';
+
+fn in_bounds(point: Point, antenna_grid: &AntennaGrid) -> bool {
+    point.y < antenna_grid.len() && point.x < antenna_grid[point.y].len()
+}
+
+// The antinode on the far side of `second` from `first`.
+fn antinode(first: Point, second: Point, antenna_grid: &AntennaGrid) -> Option<Point> {
+    second
+        .checked_add(second - first)
+        .filter(|point| in_bounds(*point, antenna_grid))
+}
+
+fn parse_input(input: &str) -> Result<AntennaGrid, ParseError> {
+    let grid = Grid::parse(input, |c, _| match c {
+        EMPTY_ANTENNA => Ok(c),
+        _ if c.is_ascii_alphanumeric() => Ok(c),
+        _ => Err("invalid character"),
+    })
+    .map_err(|error| ParseError::from(error).expected("an antenna or '.'"))?;
+    Ok(grid.rows().map(|row| row.to_vec()).collect())
+}
+
+fn calculate_antenna_antinodes(
+    antenna: Antenna,
+    position: Point,
+    antenna_grid: &AntennaGrid,
+) -> HashSet<Point> {
+    let mut antinode_locations: HashSet<Point> = HashSet::new();
+
+    for (y, x_vector) in antenna_grid.iter().enumerate() {
+        for other_position in x_vector.iter().enumerate().filter_map(|(x, coordinate)| {
+            let other_position = Point::new(x, y);
+            if *coordinate == antenna && other_position != position {
+                Some(other_position)
+            } else {
+                None
+            }
+        }) {
+            if let Some(calculated) = antinode(position, other_position, antenna_grid) {
+                antinode_locations.insert(calculated);
+            }
+        }
+    }
+
+    antinode_locations
+}
+
+fn calculate_antenna_antinodes_repeating(
+    antenna: Antenna,
+    position: Point,
+    antenna_grid: &AntennaGrid,
+) -> HashSet<Point> {
+    let mut antinode_locations: HashSet<Point> = HashSet::new();
+
+    for (y, x_vector) in antenna_grid.iter().enumerate() {
+        for other_position in x_vector.iter().enumerate().filter_map(|(x, coordinate)| {
+            if *coordinate == antenna {
+                let other_position = Point::new(x, y);
+                Some(other_position)
+            } else {
+                None
+            }
+        }) {
+            let mut old = position;
+            let mut new = other_position;
+
+            if new == old {
+                antinode_locations.insert(other_position);
+                continue;
+            }
+
+            while let Some(calculated) = antinode(old, new, antenna_grid) {
+                antinode_locations.insert(calculated);
+
+                if calculated == new {
+                    // Only one node on the antenna itself
+                    break;
+                }
+
+                old = new;
+                new = calculated;
+            }
+        }
+    }
+
+    antinode_locations
+}
+
+fn solution_1(antenna_grid: &AntennaGrid) -> u32 {
+    let mut antinode_locations = HashSet::new();
+
+    for (y, x_vector) in antenna_grid.iter().enumerate() {
+        for (x, antenna) in x_vector.iter().enumerate() {
+            if *antenna != EMPTY_ANTENNA {
+                antinode_locations.extend(calculate_antenna_antinodes(
+                    *antenna,
+                    Point::new(x, y),
+                    antenna_grid,
+                ))
+            }
+        }
+    }
+
+    antinode_locations.len() as u32
+}
+
+fn solution_2(antenna_grid: &AntennaGrid) -> u32 {
+    let mut antinode_locations = HashSet::new();
+
+    for (y, x_vector) in antenna_grid.iter().enumerate() {
+        for (x, antenna) in x_vector.iter().enumerate() {
+            if *antenna != EMPTY_ANTENNA {
+                antinode_locations.extend(calculate_antenna_antinodes_repeating(
+                    *antenna,
+                    Point::new(x, y),
+                    antenna_grid,
+                ))
+            }
+        }
+    }
+
+    antinode_locations.len() as u32
+}
+
+const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = AntennaGrid;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new("example", EXAMPLE).part1("14").part2("34")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solution_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solution_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, Day8};
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day8>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day8>();
+    }
+
+    #[test]
+    fn invalid_maps() {
+        let error = parse_input("..a.\n.#..\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+        let error = parse_input("..a.\n...\n").unwrap_err();
+        assert_eq!(error.message, "ragged row");
+    }
+}
@@ -1,7 +1,8 @@
+use aoc_utils::parsing::lines;
+use aoc_utils::testing::Example;
+use aoc_utils::{Answer, ParseError, Solution};
 use std::collections::VecDeque;
 use std::fmt;
-use std::fs::read_to_string;
-use std::time::Instant;
 
 type FileBlocks = VecDeque<Option<usize>>;
 
@@ -9,52 +10,40 @@ pub fn add_block(blocks: &mut FileBlocks, id: Option<usize>) {
     blocks.push_back(id)
 }
 
-struct DisplayFileBlocks(FileBlocks);
-
-impl fmt::Display for DisplayFileBlocks {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut fmt_string = String::new();
-        for file in self.0.iter() {
-            let mut id_char = '.';
-            if file.is_some() {
-                id_char = file.unwrap().to_string().chars().next().unwrap();
-            }
-            fmt_string.push(id_char);
-        }
-        write!(f, "{}", fmt_string)
+// The disk map is one line of digits, alternating between a file's length
+// and the free space after it.
+fn parse_input(input: &str) -> Result<FileBlocks, ParseError> {
+    let mut map_lines = lines(input).filter(|line| !line.text.trim().is_empty());
+    let Some(line) = map_lines.next() else {
+        return Err(ParseError::new("empty input").expected("a disk map"));
+    };
+    if let Some(extra) = map_lines.next() {
+        return Err(extra
+            .error("more than one line")
+            .expected("a single disk map"));
     }
-}
 
-fn load_input(path: &str) -> FileBlocks {
+    let map = line.text.trim();
+    let lengths = map
+        .char_indices()
+        .map(|(index, c)| {
+            c.to_digit(10).ok_or_else(|| {
+                line.error_at(&map[index..index + c.len_utf8()], "invalid length")
+                    .expected("a digit")
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
     let mut file_blocks = FileBlocks::new();
-    let raw_input: Vec<char> = read_to_string(path)
-        .expect("Failed to read file")
-        .chars()
-        .collect();
-
-    // I tried a fancy iterator here but it was annoying.
-    for i in 0..(raw_input.len() / 2) {
-        let index_skip_two = i * 2;
-        let file_length = raw_input
-            .get(index_skip_two)
-            .unwrap_or(&' ')
-            .to_digit(10)
-            .unwrap_or(0);
-        let space_length = raw_input
-            .get(index_skip_two + 1)
-            .unwrap_or(&' ')
-            .to_digit(10)
-            .unwrap_or(0);
-
-        for _ in 0..file_length {
-            file_blocks.push_back(Some(i));
+    for (id, lengths) in lengths.chunks(2).enumerate() {
+        for _ in 0..lengths[0] {
+            file_blocks.push_back(Some(id));
         }
-        for _ in 0..space_length {
+        for _ in 0..lengths.get(1).copied().unwrap_or(0) {
             file_blocks.push_back(None);
         }
     }
-
-    file_blocks
+    Ok(file_blocks)
 }
 
 fn get_back_some_index(blocks: &FileBlocks) -> usize {
@@ -108,8 +97,8 @@ impl fmt::Debug for Chunk {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut fmt_string = String::new();
         let mut id_char = '.';
-        if self.id.is_some() {
-            id_char = self.id.unwrap().to_string().chars().next().unwrap();
+        if let Some(id) = self.id {
+            id_char = id.to_string().chars().next().unwrap();
         }
         for _ in 0..self.length {
             fmt_string.push(id_char);
@@ -224,24 +213,61 @@ fn solution_2(file_blocks: FileBlocks) -> u64 {
     checksum(move_blocks_contiguous(file_blocks))
 }
 
-fn main() {
-    let input_start = Instant::now();
-    let file_blocks: FileBlocks = load_input("input.dat");
-    println!("input took {:?}", input_start.elapsed());
-
-    let solution_1_start = Instant::now();
-    let output_1 = solution_1(file_blocks.clone());
-    println!(
-        "solution_1: {:?}, took {:?}",
-        output_1,
-        solution_1_start.elapsed()
-    );
-
-    let solution_2_start = Instant::now();
-    let output_2 = solution_2(file_blocks.clone());
-    println!(
-        "solution_2: {:?}, took {:?}",
-        output_2,
-        solution_2_start.elapsed()
-    );
+const EXAMPLE: &str = "\
+2333133121414131402
+";
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = FileBlocks;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new("example", EXAMPLE).part1("1928").part2("2858")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solution_1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solution_2(input.clone()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, solution_1, Day9};
+    use aoc_utils::testing::{check_answers, check_examples};
+
+    #[test]
+    fn examples() {
+        check_examples::<Day9>();
+    }
+
+    #[test]
+    fn answers() {
+        check_answers::<Day9>();
+    }
+
+    #[test]
+    fn last_file_without_a_newline() {
+        let blocks = parse_input("2333133121414131402").unwrap();
+        assert_eq!(solution_1(blocks), 1928);
+    }
+
+    #[test]
+    fn lengths_must_be_digits() {
+        let error = parse_input("2333133121414131 4x2\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(17)));
+        assert_eq!(error.found.as_deref(), Some(" "));
+        let error = parse_input("23331331214141314x2\n").unwrap_err();
+        assert_eq!(error.column, Some(18));
+        assert!(parse_input("12\n34\n").is_err());
+    }
 }
@@ -0,0 +1,64 @@
+use aoc_utils::input::{Inputs, Variant};
+use aoc_utils::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Benchmarks the real input if there is one, or else the day's first example,
+// which is enough to catch regressions but not to tune on.
+fn bench_input<S: Solution>() -> Option<String> {
+    let inputs = Inputs::default();
+    match inputs.load::<S>(Variant::Real) {
+        Ok(input) => Some(input),
+        Err(error) => {
+            let example = inputs.load::<S>(Variant::Example(1)).ok()?;
+            eprintln!("{}\nbenchmarking the first example instead", error);
+            Some(example)
+        }
+    }
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion, parts: &[u8]) {
+    let Some(raw_input) = bench_input::<S>() else {
+        eprintln!("day {}: nothing to benchmark", S::DAY);
+        return;
+    };
+    let input = S::parse(&raw_input).unwrap_or_else(|error| panic!("{}", error));
+
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    // Some parts take seconds, so don't insist on the default 100 samples.
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&raw_input))));
+
+    if parts.contains(&1) {
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    }
+    if parts.contains(&2) {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c, &[1, 2]);
+    bench_day::<day2::Day2>(c, &[1, 2]);
+    bench_day::<day3::Day3>(c, &[1, 2]);
+    bench_day::<day4::Day4>(c, &[1, 2]);
+    bench_day::<day5::Day5>(c, &[1, 2]);
+    bench_day::<day6::Day6>(c, &[1, 2]);
+    bench_day::<day7::Day7>(c, &[1, 2]);
+    bench_day::<day8::Day8>(c, &[1, 2]);
+    bench_day::<day9::Day9>(c, &[1, 2]);
+    bench_day::<day10::Day10>(c, &[1, 2]);
+    bench_day::<day11::Day11>(c, &[1, 2]);
+    bench_day::<day12::Day12>(c, &[1, 2]);
+    bench_day::<day13::Day13>(c, &[1, 2]);
+    // There is no part 2 to time, only a visualisation.
+    bench_day::<day14::Day14>(c, &[1]);
+    bench_day::<day15::Day15>(c, &[1, 2]);
+    bench_day::<day16::Day16>(c, &[1, 2]);
+    // Part 2 is a brute force search that takes far too long on a real input.
+    bench_day::<day17::Day17>(c, &[1]);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
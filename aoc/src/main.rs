@@ -0,0 +1,153 @@
+use aoc_utils::input::{read_path, read_stdin, InputError, Inputs, Variant};
+use aoc_utils::{ParseError, Solution};
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use std::process::exit;
+use std::time::Instant;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solutions, timing each part.
+    Run {
+        day: u8,
+        /// Only run this part.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read this file, or stdin for `-`, instead of looking up the variant.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Which input to run on: `real`, `example`, `example2`, ...
+        #[arg(long, default_value = "real")]
+        variant: Variant,
+        /// Where to look for `<day>/<variant>.txt`. Defaults to `AOC_INPUTS`,
+        /// or `inputs` in the workspace.
+        #[arg(long)]
+        inputs: Option<PathBuf>,
+        /// Also print or write out the day's visualisation, if it has one.
+        #[arg(long)]
+        visualise: bool,
+    },
+}
+
+struct Options {
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    variant: Variant,
+    inputs: Inputs,
+    visualise: bool,
+}
+
+fn load<S: Solution>(options: &Options) -> Result<(String, String), InputError> {
+    match &options.input {
+        Some(path) if path.as_os_str() == "-" => Ok(("stdin".to_string(), read_stdin()?)),
+        Some(path) => Ok((path.display().to_string(), read_path(path)?)),
+        None => Ok((
+            format!("the {} input", options.variant),
+            options.inputs.load::<S>(options.variant)?,
+        )),
+    }
+}
+
+// Shows the line the error is on, with a caret under the column if it's known.
+fn point_at(raw_input: &str, error: &ParseError) {
+    let Some(line_number) = error.line else {
+        return;
+    };
+    let Some(line) = raw_input.lines().nth(line_number - 1) else {
+        return;
+    };
+    let gutter = line_number.to_string();
+    eprintln!("{} | {}", gutter, line);
+    if let Some(column) = error.column {
+        let width = error
+            .found
+            .as_ref()
+            .map_or(1, |found| found.chars().count().max(1));
+        eprintln!(
+            "{} | {}{}",
+            " ".repeat(gutter.len()),
+            " ".repeat(column - 1),
+            "^".repeat(width)
+        );
+    }
+}
+
+fn run<S: Solution>(options: &Options) {
+    let (source, raw_input) = load::<S>(options).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        exit(1);
+    });
+
+    let input_start = Instant::now();
+    let input = S::parse(&raw_input).unwrap_or_else(|error| {
+        eprintln!("failed to parse {}: {}", source, error);
+        point_at(&raw_input, &error);
+        exit(1);
+    });
+    println!("day {} input took {:?}", S::DAY, input_start.elapsed());
+
+    if options.part != Some(2) {
+        let part_1_start = Instant::now();
+        let answer = S::part1(&input);
+        println!("part 1: {}, took {:?}", answer, part_1_start.elapsed());
+    }
+
+    if options.part != Some(1) {
+        let part_2_start = Instant::now();
+        let answer = S::part2(&input);
+        println!("part 2: {}, took {:?}", answer, part_2_start.elapsed());
+    }
+
+    if options.visualise {
+        S::visualise(&input);
+    }
+}
+
+fn main() {
+    let Command::Run {
+        day,
+        part,
+        input,
+        variant,
+        inputs,
+        visualise,
+    } = Cli::parse().command;
+    let options = Options {
+        part,
+        input,
+        variant,
+        inputs: inputs.map(Inputs::new).unwrap_or_default(),
+        visualise,
+    };
+
+    match day {
+        1 => run::<day1::Day1>(&options),
+        2 => run::<day2::Day2>(&options),
+        3 => run::<day3::Day3>(&options),
+        4 => run::<day4::Day4>(&options),
+        5 => run::<day5::Day5>(&options),
+        6 => run::<day6::Day6>(&options),
+        7 => run::<day7::Day7>(&options),
+        8 => run::<day8::Day8>(&options),
+        9 => run::<day9::Day9>(&options),
+        10 => run::<day10::Day10>(&options),
+        11 => run::<day11::Day11>(&options),
+        12 => run::<day12::Day12>(&options),
+        13 => run::<day13::Day13>(&options),
+        14 => run::<day14::Day14>(&options),
+        15 => run::<day15::Day15>(&options),
+        16 => run::<day16::Day16>(&options),
+        17 => run::<day17::Day17>(&options),
+        _ => {
+            eprintln!("no solution for day {}", day);
+            exit(1);
+        }
+    }
+}
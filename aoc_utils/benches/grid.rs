@@ -0,0 +1,92 @@
+use aoc_utils::{Grid, Point};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::collections::VecDeque;
+
+// The nested layout `Grid` used before it moved to a single `Vec`, kept here
+// so the two can be compared.
+struct NestedGrid<T> {
+    rows: VecDeque<VecDeque<T>>,
+}
+
+impl<T> NestedGrid<T> {
+    fn get(&self, point: Point) -> Option<&T> {
+        self.rows.get(point.y).and_then(|row| row.get(point.x))
+    }
+}
+
+const SIZE: usize = 140;
+
+fn cell(point: Point) -> u32 {
+    ((point.x * 31 + point.y * 17) % 10) as u32
+}
+
+fn flat_grid() -> Grid<u32> {
+    let cells = (0..SIZE)
+        .flat_map(|y| (0..SIZE).map(move |x| cell(Point::new(x, y))))
+        .collect();
+    Grid::new(SIZE, SIZE, cells)
+}
+
+fn nested_grid() -> NestedGrid<u32> {
+    NestedGrid {
+        rows: (0..SIZE)
+            .map(|y| (0..SIZE).map(|x| cell(Point::new(x, y))).collect())
+            .collect(),
+    }
+}
+
+// Visits every cell along with its right and down neighbours, which is the
+// access pattern of most of the grid days.
+fn sum_with_neighbours(get: impl Fn(Point) -> Option<u32>) -> u32 {
+    let mut sum = 0;
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            sum += get(Point::new(x, y)).unwrap_or(0);
+            sum += get(Point::new(x + 1, y)).unwrap_or(0);
+            sum += get(Point::new(x, y + 1)).unwrap_or(0);
+        }
+    }
+    sum
+}
+
+fn grid_access(c: &mut Criterion) {
+    let flat = flat_grid();
+    let nested = nested_grid();
+
+    let mut group = c.benchmark_group("grid_access");
+    group.bench_function("flat", |b| {
+        b.iter(|| sum_with_neighbours(|point| black_box(&flat).get(point).copied()))
+    });
+    group.bench_function("nested", |b| {
+        b.iter(|| sum_with_neighbours(|point| black_box(&nested).get(point).copied()))
+    });
+    group.finish();
+}
+
+fn grid_rows(c: &mut Criterion) {
+    let flat = flat_grid();
+    let nested = nested_grid();
+
+    let mut group = c.benchmark_group("grid_rows");
+    group.bench_function("flat", |b| {
+        b.iter(|| {
+            black_box(&flat)
+                .rows()
+                .map(|row| row.iter().sum::<u32>())
+                .sum::<u32>()
+        })
+    });
+    group.bench_function("nested", |b| {
+        b.iter(|| {
+            black_box(&nested)
+                .rows
+                .iter()
+                .map(|row| row.iter().sum::<u32>())
+                .sum::<u32>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, grid_access, grid_rows);
+criterion_main!(benches);
@@ -0,0 +1,346 @@
+//! Exporting grids as images, and simulations as animations or contact sheets.
+//!
+//! PNG and GIF go through the `png` and `gif` crates; BMP and PPM are simple
+//! enough to write by hand.
+
+use crate::{render::Colour, Grid, Point};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Bmp,
+    Ppm,
+}
+
+impl ImageFormat {
+    pub fn from_path(path: &Path) -> Option<ImageFormat> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "png" => Some(ImageFormat::Png),
+            "bmp" => Some(ImageFormat::Bmp),
+            "ppm" => Some(ImageFormat::Ppm),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Colour>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Colour) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// One pixel per cell, coloured by `colour`.
+    pub fn from_grid<T>(grid: &Grid<T>, colour: impl Fn(&T) -> Colour) -> Image {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.iter().map(|(_, cell)| colour(cell)).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<Colour> {
+        if point.x < self.width && point.y < self.height {
+            Some(self.pixels[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    /// Does nothing for points outside the image.
+    pub fn set(&mut self, point: Point, colour: Colour) {
+        if point.x < self.width && point.y < self.height {
+            self.pixels[point.y * self.width + point.x] = colour;
+        }
+    }
+
+    /// Blows every pixel up into a `factor` x `factor` square, since a pixel
+    /// per cell is too small to see for most puzzles.
+    pub fn scaled(&self, factor: usize) -> Image {
+        let mut scaled = Image::new(self.width * factor, self.height * factor, Colour::BLACK);
+        for y in 0..scaled.height {
+            for x in 0..scaled.width {
+                scaled.pixels[y * scaled.width + x] =
+                    self.pixels[(y / factor) * self.width + x / factor];
+            }
+        }
+        scaled
+    }
+
+    fn draw(&mut self, other: &Image, left: usize, top: usize) {
+        for y in 0..other.height {
+            for x in 0..other.width {
+                self.set(
+                    Point::new(left + x, top + y),
+                    other.pixels[y * other.width + x],
+                );
+            }
+        }
+    }
+
+    fn rgb_bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|colour| [colour.r, colour.g, colour.b])
+            .collect()
+    }
+
+    /// Picks the format from the file extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is not a .png, .bmp or .ppm file", path.display()),
+            )
+        })?;
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer, format)?;
+        writer.flush()
+    }
+
+    pub fn write(&self, writer: impl Write, format: ImageFormat) -> io::Result<()> {
+        match format {
+            ImageFormat::Png => self.write_png(writer),
+            ImageFormat::Bmp => self.write_bmp(writer),
+            ImageFormat::Ppm => self.write_ppm(writer),
+        }
+    }
+
+    fn write_png(&self, writer: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.rgb_bytes())
+            .map_err(io::Error::other)
+    }
+
+    // 24 bit uncompressed, rows stored bottom to top and padded to 4 bytes.
+    fn write_bmp(&self, mut writer: impl Write) -> io::Result<()> {
+        const HEADER_SIZE: u32 = 14 + 40;
+        let row_size = (self.width * 3).div_ceil(4) * 4;
+        let image_size = (row_size * self.height) as u32;
+
+        writer.write_all(b"BM")?;
+        writer.write_all(&(HEADER_SIZE + image_size).to_le_bytes())?;
+        writer.write_all(&[0; 4])?;
+        writer.write_all(&HEADER_SIZE.to_le_bytes())?;
+
+        writer.write_all(&40u32.to_le_bytes())?;
+        writer.write_all(&(self.width as i32).to_le_bytes())?;
+        writer.write_all(&(self.height as i32).to_le_bytes())?;
+        writer.write_all(&1u16.to_le_bytes())?;
+        writer.write_all(&24u16.to_le_bytes())?;
+        writer.write_all(&0u32.to_le_bytes())?;
+        writer.write_all(&image_size.to_le_bytes())?;
+        writer.write_all(&[0; 16])?;
+
+        let mut row = Vec::with_capacity(row_size);
+        for y in (0..self.height).rev() {
+            row.clear();
+            for colour in &self.pixels[y * self.width..(y + 1) * self.width] {
+                row.extend([colour.b, colour.g, colour.r]);
+            }
+            row.resize(row_size, 0);
+            writer.write_all(&row)?;
+        }
+        Ok(())
+    }
+
+    fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.rgb_bytes())
+    }
+}
+
+/// Lays `frames` out left to right, top to bottom, `columns` to a row with
+/// `gap` pixels of `background` between them. Frames are aligned to the size
+/// of the largest.
+pub fn contact_sheet(frames: &[Image], columns: usize, gap: usize, background: Colour) -> Image {
+    let columns = columns.max(1);
+    let rows = frames.len().div_ceil(columns);
+    let frame_width = frames.iter().map(Image::width).max().unwrap_or(0);
+    let frame_height = frames.iter().map(Image::height).max().unwrap_or(0);
+
+    let mut sheet = Image::new(
+        columns * (frame_width + gap) + gap,
+        rows * (frame_height + gap) + gap,
+        background,
+    );
+    for (index, frame) in frames.iter().enumerate() {
+        let left = gap + (index % columns) * (frame_width + gap);
+        let top = gap + (index / columns) * (frame_height + gap);
+        sheet.draw(frame, left, top);
+    }
+    sheet
+}
+
+/// Writes `frames` as a looping animated GIF, showing each for `delay`
+/// hundredths of a second. Every frame must be the same size.
+pub fn save_gif(
+    frames: impl IntoIterator<Item = Image>,
+    delay: u16,
+    path: impl AsRef<Path>,
+) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_gif(frames, delay, &mut writer)?;
+    writer.flush()
+}
+
+/// Frames are encoded as they come, so a long simulation can pass them
+/// straight from an iterator without holding them all.
+pub fn write_gif(
+    frames: impl IntoIterator<Item = Image>,
+    delay: u16,
+    writer: impl Write,
+) -> io::Result<()> {
+    let mut frames = frames.into_iter();
+    let first = frames
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no frames to animate"))?;
+    let mut gif = GifWriter::new(writer, first.width, first.height, delay)?;
+    gif.write_frame(&first)?;
+    for frame in frames {
+        gif.write_frame(&frame)?;
+    }
+    Ok(())
+}
+
+/// A looping animated GIF written a frame at a time.
+pub struct GifWriter<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: u16,
+    height: u16,
+    delay: u16,
+}
+
+impl<W: Write> GifWriter<W> {
+    pub fn new(writer: W, width: usize, height: usize, delay: u16) -> io::Result<GifWriter<W>> {
+        let width = u16::try_from(width).map_err(|_| invalid("frames too wide for a GIF"))?;
+        let height = u16::try_from(height).map_err(|_| invalid("frames too tall for a GIF"))?;
+        let mut encoder =
+            gif::Encoder::new(writer, width, height, &[]).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        Ok(GifWriter {
+            encoder,
+            width,
+            height,
+            delay,
+        })
+    }
+
+    pub fn write_frame(&mut self, frame: &Image) -> io::Result<()> {
+        if frame.width != self.width as usize || frame.height != self.height as usize {
+            return Err(invalid("every frame must be the same size"));
+        }
+
+        // Puzzle frames rarely use more than a handful of colours, so give
+        // each frame an exact palette of its own when it fits and only
+        // quantise the ones that don't.
+        let mut palette: HashMap<Colour, u8> = HashMap::new();
+        let mut indices = Vec::with_capacity(frame.pixels.len());
+        for colour in &frame.pixels {
+            let next_index = palette.len();
+            let index = *palette.entry(*colour).or_insert(next_index as u8);
+            if palette.len() > 256 {
+                break;
+            }
+            indices.push(index);
+        }
+
+        let mut gif_frame = if palette.len() <= 256 {
+            let mut colours: Vec<(&Colour, &u8)> = palette.iter().collect();
+            colours.sort_by_key(|(_, index)| **index);
+            let colours = colours
+                .iter()
+                .flat_map(|(colour, _)| [colour.r, colour.g, colour.b])
+                .collect();
+            gif::Frame {
+                palette: Some(colours),
+                ..gif::Frame::from_indexed_pixels(self.width, self.height, indices, None)
+            }
+        } else {
+            gif::Frame::from_rgb_speed(self.width, self.height, &frame.rgb_bytes(), 10)
+        };
+        gif_frame.delay = self.delay;
+        self.encoder
+            .write_frame(&gif_frame)
+            .map_err(io::Error::other)
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_gif, Image};
+    use crate::render::Colour;
+
+    fn frame(colour: Colour) -> Image {
+        let mut image = Image::new(3, 2, Colour::BLACK);
+        image.set(crate::Point::new(1, 1), colour);
+        image
+    }
+
+    #[test]
+    fn gif_frames_round_trip() {
+        let colours = [Colour::RED, Colour::GREEN, Colour::BLUE];
+        let mut gif = Vec::new();
+        write_gif(colours.map(frame), 5, &mut gif).unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(gif.as_slice()).unwrap();
+        let mut decoded = Vec::new();
+        while let Some(gif_frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(
+                (gif_frame.width, gif_frame.height, gif_frame.delay),
+                (3, 2, 5)
+            );
+            // The pixel at (1, 1), in RGBA.
+            decoded.push(gif_frame.buffer[4 * 4..4 * 4 + 3].to_vec());
+        }
+        let expected: Vec<Vec<u8>> = colours
+            .iter()
+            .map(|colour| vec![colour.r, colour.g, colour.b])
+            .collect();
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn gif_frames_must_match() {
+        let frames = [frame(Colour::RED), Image::new(2, 2, Colour::BLACK)];
+        assert!(write_gif(frames, 5, Vec::new()).is_err());
+        assert!(write_gif([], 5, Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,346 @@
+//! Finding a day's puzzle input, wherever it's run from.
+//!
+//! Inputs live in an inputs directory, `<workspace>/inputs` unless `AOC_INPUTS`
+//! or `Inputs::new` says otherwise, as `<day>/<variant>.txt`:
+//!
+//! ```text
+//! inputs/6/real.txt
+//! inputs/6/example.txt
+//! inputs/6/example2.txt
+//! ```
+//!
+//! The real input is also found at `<workspace>/<day>/input.dat`, where the
+//! days used to read it from, and examples fall back to the ones the day
+//! registers in `Solution::EXAMPLES`.
+
+use crate::Solution;
+use std::{
+    env,
+    error::Error,
+    fmt,
+    fs::read_to_string,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Which of a day's inputs to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Real,
+    /// The 1-based example, `example` being the first and `example2` the
+    /// second.
+    Example(usize),
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Variant::Real => write!(f, "real"),
+            Variant::Example(1) => write!(f, "example"),
+            Variant::Example(number) => write!(f, "example{}", number),
+        }
+    }
+}
+
+impl FromStr for Variant {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Variant, String> {
+        let invalid = || format!("{:?} is not real, example or exampleN", name);
+        match name {
+            "real" => Ok(Variant::Real),
+            "example" => Ok(Variant::Example(1)),
+            _ => {
+                let number = name.strip_prefix("example").ok_or_else(invalid)?;
+                match number.parse() {
+                    Ok(number) if number > 0 => Ok(Variant::Example(number)),
+                    _ => Err(invalid()),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        day: u8,
+        variant: Variant,
+        looked_in: Vec<PathBuf>,
+        registered_examples: usize,
+    },
+    Read {
+        /// `None` for stdin.
+        path: Option<PathBuf>,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound {
+                day,
+                variant,
+                looked_in,
+                registered_examples,
+            } => {
+                write!(f, "no {} input for day {}, looked in:", variant, day)?;
+                for path in looked_in {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                if let Variant::Example(_) = variant {
+                    write!(
+                        f,
+                        "\nand the day registers {} example(s)",
+                        registered_examples
+                    )?;
+                }
+                Ok(())
+            }
+            InputError::Read {
+                path: Some(path),
+                error,
+            } => write!(f, "failed to read {}: {}", path.display(), error),
+            InputError::Read { path: None, error } => {
+                write!(f, "failed to read stdin: {}", error)
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Read { error, .. } => Some(error),
+        }
+    }
+}
+
+/// The workspace this crate was built in, which the default locations are
+/// relative to.
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc_utils is inside the workspace")
+}
+
+pub fn read_path(path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref();
+    read_to_string(path).map_err(|error| InputError::Read {
+        path: Some(path.to_path_buf()),
+        error,
+    })
+}
+
+pub fn read_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|error| InputError::Read { path: None, error })?;
+    Ok(input)
+}
+
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Default for Inputs {
+    /// `AOC_INPUTS` if it's set, otherwise `<workspace>/inputs`.
+    fn default() -> Inputs {
+        match env::var_os("AOC_INPUTS") {
+            Some(dir) => Inputs::new(dir),
+            None => Inputs::new(workspace_dir().join("inputs")),
+        }
+    }
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Inputs {
+        Inputs { dir: dir.into() }
+    }
+
+    /// The files that could hold the input, in the order they're tried.
+    pub fn paths(&self, day: u8, variant: Variant) -> Vec<PathBuf> {
+        let mut paths = vec![self
+            .dir
+            .join(day.to_string())
+            .join(format!("{}.txt", variant))];
+        if variant == Variant::Real {
+            paths.push(workspace_dir().join(day.to_string()).join("input.dat"));
+        }
+        paths
+    }
+
+    pub fn load<S: Solution>(&self, variant: Variant) -> Result<String, InputError> {
+        let paths = self.paths(S::DAY, variant);
+        for path in &paths {
+            if path.is_file() {
+                return read_path(path);
+            }
+        }
+
+        // Examples are numbered from 1, so `Example(0)` is never registered.
+        if let Variant::Example(number) = variant {
+            let example = number
+                .checked_sub(1)
+                .and_then(|index| S::EXAMPLES.get(index));
+            if let Some(example) = example {
+                return Ok(example.input.to_string());
+            }
+        }
+
+        Err(InputError::NotFound {
+            day: S::DAY,
+            variant,
+            looked_in: paths,
+            registered_examples: S::EXAMPLES.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{workspace_dir, InputError, Inputs, Variant};
+    use crate::testing::Example;
+    use crate::{Answer, ParseError, Solution};
+    use std::{env, fs, path::PathBuf, process};
+
+    // A day number nothing in the workspace uses, so only the test's own
+    // directory can hold its inputs.
+    struct Unused;
+
+    impl Solution for Unused {
+        const DAY: u8 = 200;
+
+        type Input = ();
+
+        const EXAMPLES: &'static [Example] = &[Example::new("example", "registered")];
+
+        fn parse(_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Answer {
+            Answer::NotImplemented
+        }
+    }
+
+    /// An empty inputs directory of the test's own, removed when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let dir = env::temp_dir().join(format!("aoc_utils-{}-{}", name, process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+
+        fn write(&self, day: u8, file: &str, contents: &str) {
+            let dir = self.0.join(day.to_string());
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join(file), contents).unwrap();
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn variant_round_trip() {
+        for variant in [Variant::Real, Variant::Example(1), Variant::Example(12)] {
+            assert_eq!(variant.to_string().parse(), Ok(variant));
+        }
+        assert_eq!(Variant::Example(1).to_string(), "example");
+        assert_eq!(Variant::Example(2).to_string(), "example2");
+        assert_eq!("example1".parse(), Ok(Variant::Example(1)));
+        for invalid in ["", "Real", "example0", "example-1", "examples", "input"] {
+            assert!(invalid.parse::<Variant>().is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn candidate_paths_in_order() {
+        let inputs = Inputs::new("/inputs");
+        assert_eq!(
+            inputs.paths(6, Variant::Real),
+            [
+                PathBuf::from("/inputs/6/real.txt"),
+                workspace_dir().join("6/input.dat")
+            ]
+        );
+        assert_eq!(
+            inputs.paths(6, Variant::Example(2)),
+            [PathBuf::from("/inputs/6/example2.txt")]
+        );
+    }
+
+    #[test]
+    fn files_before_registered_examples() {
+        let dir = TempDir::new("files-first");
+        let inputs = Inputs::new(&dir.0);
+        assert_eq!(
+            inputs.load::<Unused>(Variant::Example(1)).unwrap(),
+            "registered"
+        );
+
+        dir.write(200, "example.txt", "from a file");
+        dir.write(200, "real.txt", "the real one");
+        assert_eq!(
+            inputs.load::<Unused>(Variant::Example(1)).unwrap(),
+            "from a file"
+        );
+        assert_eq!(
+            inputs.load::<Unused>(Variant::Real).unwrap(),
+            "the real one"
+        );
+    }
+
+    #[test]
+    fn not_found_names_every_path() {
+        let dir = TempDir::new("not-found");
+        let inputs = Inputs::new(&dir.0);
+
+        for variant in [Variant::Real, Variant::Example(2)] {
+            let error = inputs.load::<Unused>(variant).unwrap_err();
+            let InputError::NotFound { looked_in, .. } = &error else {
+                panic!("expected NotFound, got {:?}", error);
+            };
+            assert_eq!(looked_in, &inputs.paths(200, variant));
+
+            let message = error.to_string();
+            assert!(message.starts_with(&format!("no {} input for day 200", variant)));
+            for path in looked_in {
+                assert!(message.contains(&path.display().to_string()), "{}", message);
+            }
+        }
+
+        let error = inputs.load::<Unused>(Variant::Example(2)).unwrap_err();
+        assert!(error.to_string().ends_with("registers 1 example(s)"));
+    }
+
+    #[test]
+    fn example_zero_is_not_found() {
+        let dir = TempDir::new("example-zero");
+        let inputs = Inputs::new(&dir.0);
+        let error = inputs.load::<Unused>(Variant::Example(0)).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                InputError::NotFound {
+                    registered_examples: 1,
+                    ..
+                }
+            ),
+            "{:?}",
+            error
+        );
+    }
+}
@@ -1,6 +1,20 @@
+pub mod image;
+pub mod input;
+pub mod parsing;
+pub mod render;
+pub mod search;
+pub mod solution;
+pub mod stencil;
+pub mod testing;
+pub mod word_search;
+
+pub use solution::{Answer, ParseError, Solution};
+
 use std::{
-    collections::VecDeque,
-    ops::{Index, IndexMut},
+    collections::BTreeMap,
+    error::Error,
+    fmt,
+    ops::{Add, Index, IndexMut, Mul, Neg, Sub},
 };
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Clone, Copy, Hash)]
@@ -9,7 +23,13 @@ pub struct Point {
     pub y: usize,
 }
 
-#[derive(Clone, Eq, PartialEq, Debug, Copy, Ord, PartialOrd)]
+#[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Clone, Copy, Hash, Default)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Copy, Ord, PartialOrd, Hash)]
 pub enum Direction {
     Up,
     Down,
@@ -17,6 +37,173 @@ pub enum Direction {
     Right,
 }
 
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction> {
+        Direction::ALL.into_iter()
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn to_vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::Down => Vector::new(0, 1),
+            Direction::Left => Vector::new(-1, 0),
+            Direction::Right => Vector::new(1, 0),
+        }
+    }
+
+    /// The arrow used for this direction in puzzle inputs.
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+}
+
+/// Turning by the smallest step the direction type allows: a quarter turn for
+/// `Direction`, an eighth for `Direction8`.
+pub trait Turn {
+    fn turn_right(&self) -> Self;
+    fn turn_left(&self) -> Self;
+}
+
+impl Turn for Direction {
+    fn turn_right(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    fn turn_left(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+}
+
+/// Accepts arrows (`^v<>`), `UDLR` and compass points (`NSEW`).
+impl TryFrom<char> for Direction {
+    type Error = InvalidDirection;
+
+    fn try_from(c: char) -> Result<Direction, InvalidDirection> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            'v' | 'D' | 'S' => Ok(Direction::Down),
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            _ => Err(InvalidDirection(c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDirection(pub char);
+
+impl fmt::Display for InvalidDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a direction", self.0)
+    }
+}
+
+impl Error for InvalidDirection {}
+
+/// The four orthogonal directions plus the diagonals between them, clockwise
+/// from `Up`.
+#[derive(Clone, Eq, PartialEq, Debug, Copy, Ord, PartialOrd, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction8> {
+        Direction8::ALL.into_iter()
+    }
+
+    pub fn opposite(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn to_vector(self) -> Vector {
+        match self {
+            Direction8::Up => Vector::new(0, -1),
+            Direction8::UpRight => Vector::new(1, -1),
+            Direction8::Right => Vector::new(1, 0),
+            Direction8::DownRight => Vector::new(1, 1),
+            Direction8::Down => Vector::new(0, 1),
+            Direction8::DownLeft => Vector::new(-1, 1),
+            Direction8::Left => Vector::new(-1, 0),
+            Direction8::UpLeft => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl Turn for Direction8 {
+    fn turn_right(&self) -> Direction8 {
+        Direction8::ALL[(*self as usize + 1) % 8]
+    }
+
+    fn turn_left(&self) -> Direction8 {
+        Direction8::ALL[(*self as usize + 7) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+            Direction::Right => Direction8::Right,
+        }
+    }
+}
+
 impl Point {
     pub fn new(x: usize, y: usize) -> Point {
         Point { x, y }
@@ -27,6 +214,8 @@ impl Point {
             || (self.y == other.y && (self.x as isize - other.x as isize).abs() == 1)
     }
 
+    /// Panics stepping up or left from the top or left edge; use
+    /// `checked_step` where that can happen.
     pub fn up(&self) -> Point {
         Point::new(self.x, self.y - 1)
     }
@@ -48,34 +237,310 @@ impl Point {
             Direction::Right => self.right(),
         }
     }
+
+    /// Returns `None` rather than underflowing when the result would leave the
+    /// positive quadrant.
+    pub fn checked_add(&self, vector: Vector) -> Option<Point> {
+        Some(Point::new(
+            self.x.checked_add_signed(vector.x)?,
+            self.y.checked_add_signed(vector.y)?,
+        ))
+    }
+
+    pub fn checked_step(&self, direction: Direction) -> Option<Point> {
+        self.checked_add(direction.to_vector())
+    }
+
+    /// Adds the vector then wraps around a `width` x `height` torus, so
+    /// stepping off one edge comes back in on the opposite one. Returns `None`
+    /// for an empty grid.
+    pub fn wrapping_add_mod(&self, vector: Vector, width: usize, height: usize) -> Option<Point> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let wrap = |position: usize, delta: isize, bound: usize| -> usize {
+            let bound = bound as i128;
+            (position as i128 + delta as i128).rem_euclid(bound) as usize
+        };
+        Some(Point::new(
+            wrap(self.x, vector.x, width),
+            wrap(self.y, vector.y, height),
+        ))
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(
+            self.x as isize - other.x as isize,
+            self.y as isize - other.y as isize,
+        )
+    }
+}
+
+impl Vector {
+    pub fn new(x: isize, y: isize) -> Vector {
+        Vector { x, y }
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, scalar: isize) -> Vector {
+        Vector::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
 }
 
+/// A rectangular grid stored row-major in a single `Vec`.
 #[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Clone, Hash)]
 pub struct Grid<T> {
-    pub rows: VecDeque<VecDeque<T>>,
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
 }
 
 impl<T> Grid<T> {
-    pub fn new(rows: VecDeque<VecDeque<T>>) -> Grid<T> {
-        Grid { rows }
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        if point.x < self.width && point.y < self.height {
+            Some(point.y * self.width + point.x)
+        } else {
+            None
+        }
     }
 
     pub fn get(&self, point: Point) -> Option<&T> {
-        self.rows.get(point.y).and_then(|row| row.get(point.x))
+        self.index_of(point).map(|index| &self.cells[index])
     }
 
     pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
-        self.rows
-            .get_mut(point.y)
-            .and_then(|row| row.get_mut(point.x))
+        self.index_of(point).map(|index| &mut self.cells[index])
     }
 
     pub fn width(&self) -> usize {
-        self.rows.front().map_or(0, |row| row.len())
+        self.width
     }
 
     pub fn height(&self) -> usize {
-        self.rows.len()
+        self.height
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        if y < self.height {
+            Some(&mut self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Columns aren't contiguous, so this steps through the cells top to bottom.
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        if x < self.width {
+            Some(self.cells[x..].iter().step_by(self.width))
+        } else {
+            None
+        }
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The up/down/left/right neighbours of `point` that lie inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Direction, Point, &T)> {
+        Direction::iter().filter_map(move |direction| {
+            let neighbour = point.checked_step(direction)?;
+            Some((direction, neighbour, self.get(neighbour)?))
+        })
+    }
+
+    /// As `neighbours4`, but including the diagonals.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Direction8, Point, &T)> {
+        Direction8::iter().filter_map(move |direction| {
+            let neighbour = point.checked_add(direction.to_vector())?;
+            Some((direction, neighbour, self.get(neighbour)?))
+        })
+    }
+
+    /// Builds a grid from one line of text per row, mapping each character
+    /// with `parse_cell`. Errors carry the 1-based line and column.
+    pub fn parse<E>(
+        input: &str,
+        parse_cell: impl Fn(char, Point) -> Result<T, E>,
+    ) -> Result<Grid<T>, GridParseError<E>> {
+        Grid::parse_with_markers(input, &[], parse_cell).map(|(grid, _)| grid)
+    }
+
+    /// As `parse`, but also records where each of the `markers` characters
+    /// appeared. Marker characters are still passed to `parse_cell`, which
+    /// decides what cell they stand on (e.g. the guard `^` is on empty floor).
+    pub fn parse_with_markers<E>(
+        input: &str,
+        markers: &[char],
+        parse_cell: impl Fn(char, Point) -> Result<T, E>,
+    ) -> Result<(Grid<T>, Markers), GridParseError<E>> {
+        let mut cells = Vec::new();
+        let mut found_markers = Markers::default();
+        let mut expected_width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let row_start = cells.len();
+            for (x, character) in line.chars().enumerate() {
+                let point = Point::new(x, y);
+                if markers.contains(&character) {
+                    found_markers.insert(character, point);
+                }
+                let cell = parse_cell(character, point).map_err(|error| GridParseError {
+                    line: y + 1,
+                    column: x + 1,
+                    kind: GridParseErrorKind::InvalidCell { character, error },
+                })?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - row_start;
+            let expected = *expected_width.get_or_insert(row_width);
+            if row_width != expected {
+                return Err(GridParseError {
+                    line: y + 1,
+                    column: row_width.min(expected) + 1,
+                    kind: GridParseErrorKind::RaggedRow {
+                        expected_width: expected,
+                    },
+                });
+            }
+            height += 1;
+        }
+
+        let width = expected_width.unwrap_or(0);
+        Ok((Grid::new(width, height, cells), found_markers))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridParseError<E> {
+    pub line: usize,
+    pub column: usize,
+    pub kind: GridParseErrorKind<E>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridParseErrorKind<E> {
+    InvalidCell { character: char, error: E },
+    RaggedRow { expected_width: usize },
+}
+
+impl<E: fmt::Display> fmt::Display for GridParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            GridParseErrorKind::InvalidCell { character, error } => {
+                write!(f, "{}, found {:?}", error, character)
+            }
+            GridParseErrorKind::RaggedRow { expected_width } => {
+                write!(f, "expected a row of width {}", expected_width)
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> Error for GridParseError<E> {}
+
+/// Positions of marker characters found by `Grid::parse_with_markers`, in
+/// reading order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markers {
+    positions: BTreeMap<char, Vec<Point>>,
+    /// Every marker, so `iter` can go in reading order rather than by
+    /// character.
+    in_order: Vec<(char, Point)>,
+}
+
+impl Markers {
+    fn insert(&mut self, marker: char, point: Point) {
+        self.positions.entry(marker).or_default().push(point);
+        self.in_order.push((marker, point));
+    }
+
+    pub fn get(&self, marker: char) -> &[Point] {
+        self.positions.get(&marker).map_or(&[], |points| points)
+    }
+
+    pub fn first(&self, marker: char) -> Option<Point> {
+        self.get(marker).first().copied()
+    }
+
+    /// Every marker of any character, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (char, Point)> + '_ {
+        self.in_order.iter().copied()
     }
 }
 
@@ -83,12 +548,254 @@ impl<T> Index<Point> for Grid<T> {
     type Output = T;
 
     fn index(&self, point: Point) -> &Self::Output {
-        &self.rows[point.y][point.x]
+        match self.index_of(point) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "{:?} is outside a {}x{} grid",
+                point, self.width, self.height
+            ),
+        }
     }
 }
 
 impl<T> IndexMut<Point> for Grid<T> {
     fn index_mut(&mut self, point: Point) -> &mut Self::Output {
-        &mut self.rows[point.y][point.x]
+        match self.index_of(point) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "{:?} is outside a {}x{} grid",
+                point, self.width, self.height
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Direction8, Grid, GridParseError, GridParseErrorKind, Point, Vector};
+
+    #[test]
+    fn checked_add_stops_at_the_edge() {
+        let corner = Point::new(0, 0);
+        assert_eq!(corner.checked_add(Vector::new(-1, 0)), None);
+        assert_eq!(corner.checked_add(Vector::new(0, -1)), None);
+        assert_eq!(corner.checked_step(Direction::Up), None);
+        assert_eq!(corner.checked_step(Direction::Left), None);
+        assert_eq!(
+            corner.checked_step(Direction::Right),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(
+            Point::new(3, 2).checked_add(Vector::new(-3, -2)),
+            Some(Point::new(0, 0))
+        );
+        assert_eq!(
+            Point::new(usize::MAX, 0).checked_add(Vector::new(1, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn wrapping_add_mod_wraps_both_ways() {
+        let point = Point::new(0, 1);
+        assert_eq!(
+            point.wrapping_add_mod(Vector::new(-1, -2), 4, 3),
+            Some(Point::new(3, 2))
+        );
+        assert_eq!(
+            point.wrapping_add_mod(Vector::new(9, 5), 4, 3),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(
+            point.wrapping_add_mod(Vector::new(-9, -7), 4, 3),
+            Some(Point::new(3, 0))
+        );
+        assert_eq!(point.wrapping_add_mod(Vector::new(1, 1), 0, 3), None);
+        assert_eq!(point.wrapping_add_mod(Vector::new(1, 1), 4, 0), None);
+    }
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Vector::new(2, -3);
+        let b = Vector::new(-1, 5);
+        assert_eq!(a + b, Vector::new(1, 2));
+        assert_eq!(a - b, Vector::new(3, -8));
+        assert_eq!(a * 3, Vector::new(6, -9));
+        assert_eq!(a * -1, -a);
+        assert_eq!(-a, Vector::new(-2, 3));
+        assert_eq!(Point::new(1, 4) - Point::new(3, 2), Vector::new(-2, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn stepping_up_from_the_top_edge_panics() {
+        Point::new(2, 0).adjacent(Direction::Up);
+    }
+
+    fn digit(cell: char, _: Point) -> Result<u32, &'static str> {
+        cell.to_digit(10).ok_or("not a digit")
+    }
+
+    #[test]
+    fn parse_errors_carry_line_and_column() {
+        assert_eq!(
+            Grid::parse("123\n45\n", digit),
+            Err(GridParseError {
+                line: 2,
+                column: 3,
+                kind: GridParseErrorKind::RaggedRow { expected_width: 3 },
+            })
+        );
+        assert_eq!(
+            Grid::parse("12\n345\n", digit),
+            Err(GridParseError {
+                line: 2,
+                column: 3,
+                kind: GridParseErrorKind::RaggedRow { expected_width: 2 },
+            })
+        );
+        let error = Grid::parse("12\n3x\n", digit).unwrap_err();
+        assert_eq!(
+            error,
+            GridParseError {
+                line: 2,
+                column: 2,
+                kind: GridParseErrorKind::InvalidCell {
+                    character: 'x',
+                    error: "not a digit",
+                },
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: not a digit, found 'x'"
+        );
+    }
+
+    #[test]
+    fn markers_in_reading_order() {
+        let (_, markers) = Grid::parse_with_markers("b.a\n.a.\nb..\n", &['a', 'b'], |cell, _| {
+            Ok::<_, &str>(cell)
+        })
+        .unwrap();
+        assert_eq!(
+            markers.iter().collect::<Vec<_>>(),
+            [
+                ('b', Point::new(0, 0)),
+                ('a', Point::new(2, 0)),
+                ('a', Point::new(1, 1)),
+                ('b', Point::new(0, 2)),
+            ]
+        );
+        assert_eq!(markers.get('a'), [Point::new(2, 0), Point::new(1, 1)]);
+        assert_eq!(markers.first('b'), Some(Point::new(0, 0)));
+        assert_eq!(markers.get('c'), []);
+    }
+
+    fn numbered(width: usize, height: usize) -> Grid<usize> {
+        Grid::new(width, height, (0..width * height).collect())
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = numbered(3, 4);
+        assert_eq!(grid.row(0), Some(&[0, 1, 2][..]));
+        assert_eq!(grid.row(3), Some(&[9, 10, 11][..]));
+        assert_eq!(grid.row(4), None);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [&[0, 1, 2][..], &[3, 4, 5], &[6, 7, 8], &[9, 10, 11]]
+        );
+
+        // Each column steps a whole row at a time through the flat storage.
+        let column = |x| {
+            grid.column(x)
+                .map(|cells| cells.copied().collect::<Vec<_>>())
+        };
+        assert_eq!(column(0), Some(vec![0, 3, 6, 9]));
+        assert_eq!(column(2), Some(vec![2, 5, 8, 11]));
+        assert_eq!(column(3), None);
+
+        let mut grid = grid;
+        grid.row_mut(1).unwrap()[2] = 50;
+        assert_eq!(grid[Point::new(2, 1)], 50);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 4)), None);
+    }
+
+    #[test]
+    fn single_column_grid() {
+        let grid = numbered(1, 3);
+        assert_eq!(grid.column(0).unwrap().count(), 3);
+        assert_eq!(grid.rows().count(), 3);
+        assert!(numbered(0, 0).rows().next().is_none());
+    }
+
+    #[test]
+    fn neighbours4_in_order_and_in_bounds() {
+        let grid = numbered(3, 3);
+        let around = |point| grid.neighbours4(point).collect::<Vec<_>>();
+        assert_eq!(
+            around(Point::new(1, 1)),
+            [
+                (Direction::Up, Point::new(1, 0), &1),
+                (Direction::Down, Point::new(1, 2), &7),
+                (Direction::Left, Point::new(0, 1), &3),
+                (Direction::Right, Point::new(2, 1), &5),
+            ]
+        );
+        assert_eq!(
+            around(Point::new(0, 0)),
+            [
+                (Direction::Down, Point::new(0, 1), &3),
+                (Direction::Right, Point::new(1, 0), &1),
+            ]
+        );
+        assert_eq!(
+            around(Point::new(2, 2)),
+            [
+                (Direction::Up, Point::new(2, 1), &5),
+                (Direction::Left, Point::new(1, 2), &7),
+            ]
+        );
+        assert_eq!(around(Point::new(2, 1)).len(), 3);
+        assert!(numbered(1, 1)
+            .neighbours4(Point::new(0, 0))
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn neighbours8_clockwise_from_up() {
+        let grid = numbered(3, 3);
+        let directions = |point| {
+            grid.neighbours8(point)
+                .map(|(direction, _, _)| direction)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(directions(Point::new(1, 1)), Direction8::ALL);
+        assert_eq!(
+            grid.neighbours8(Point::new(1, 1))
+                .map(|(_, _, cell)| *cell)
+                .collect::<Vec<_>>(),
+            [1, 2, 5, 8, 7, 6, 3, 0]
+        );
+        assert_eq!(
+            directions(Point::new(0, 0)),
+            [Direction8::Right, Direction8::DownRight, Direction8::Down]
+        );
+        assert_eq!(
+            directions(Point::new(2, 0)),
+            [Direction8::Down, Direction8::DownLeft, Direction8::Left]
+        );
+        assert_eq!(
+            directions(Point::new(0, 2)),
+            [Direction8::Up, Direction8::UpRight, Direction8::Right]
+        );
+        assert_eq!(
+            directions(Point::new(2, 2)),
+            [Direction8::Up, Direction8::Left, Direction8::UpLeft]
+        );
+        assert_eq!(directions(Point::new(1, 0)).len(), 5);
     }
 }
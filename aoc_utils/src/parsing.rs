@@ -0,0 +1,318 @@
+//! Helpers for the shapes puzzle inputs keep coming in, all of which keep
+//! track of line numbers so a `ParseError` can point at the offending text.
+//!
+//! ```
+//! use aoc_utils::parsing::{lines, Pattern};
+//!
+//! const ROBOT: Pattern = Pattern::new("p={},{} v={},{}");
+//!
+//! let line = lines("p=0,4 v=3,-3").next().unwrap();
+//! let fields = ROBOT.matches(line).unwrap();
+//! assert_eq!(fields.parse::<i32>(3, "a number").unwrap(), -3);
+//! assert_eq!(line.integers::<i32>().unwrap(), [0, 4, 3, -3]);
+//! ```
+
+use crate::ParseError;
+use std::str::FromStr;
+
+/// A line of the input and its 1-based line number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+/// Groups the lines between blank lines, dropping the blank lines themselves.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = Vec::new();
+    let mut section = Vec::new();
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !section.is_empty() {
+                sections.push(std::mem::take(&mut section));
+            }
+        } else {
+            section.push(line);
+        }
+    }
+    if !section.is_empty() {
+        sections.push(section);
+    }
+    sections
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::at_line(self.number, message)
+    }
+
+    /// An error pointing at `token`, which should be a slice of this line.
+    pub fn error_at(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at_token(self.number, self.text, token, message)
+    }
+
+    /// Parses `token`, a slice of this line, as `expected`.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token
+            .parse()
+            .map_err(|_| self.error_at(token, "invalid value").expected(expected))
+    }
+
+    /// Parses every whitespace separated word as `expected`.
+    pub fn words<T: FromStr>(&self, expected: &str) -> Result<Vec<T>, ParseError> {
+        self.text
+            .split_whitespace()
+            .map(|word| self.parse(word, expected))
+            .collect()
+    }
+
+    /// Parses every piece between `separator`s as `expected`.
+    pub fn split<T: FromStr>(&self, separator: &str, expected: &str) -> Result<Vec<T>, ParseError> {
+        self.text
+            .split(separator)
+            .map(|piece| self.parse(piece, expected))
+            .collect()
+    }
+
+    /// Every integer in the line, with a `-` in front making it negative,
+    /// ignoring whatever is around them.
+    pub fn integers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        let bytes = self.text.as_bytes();
+        let mut integers = Vec::new();
+        let mut index = 0;
+        while index < bytes.len() {
+            let negative = bytes[index] == b'-'
+                && bytes
+                    .get(index + 1)
+                    .is_some_and(|byte| byte.is_ascii_digit());
+            if !negative && !bytes[index].is_ascii_digit() {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            index += 1;
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                index += 1;
+            }
+            integers.push(self.parse(&self.text[start..index], "an integer in range")?);
+        }
+        Ok(integers)
+    }
+}
+
+/// A line shape with `{}` standing for each field, e.g. `"{}|{}"` or
+/// `"Register {}: {}"`. A field runs up to the first place the text after it
+/// turns up, or to the end of the line for a trailing field. Two fields can't
+/// be next to each other, as there'd be no telling where one ends.
+#[derive(Debug, Clone, Copy)]
+pub struct Pattern {
+    pattern: &'static str,
+}
+
+impl Pattern {
+    /// Panics on adjacent fields, which for a `const` pattern fails the build.
+    pub const fn new(pattern: &'static str) -> Pattern {
+        let bytes = pattern.as_bytes();
+        let mut index = 0;
+        while index + 4 <= bytes.len() {
+            if bytes[index] == b'{'
+                && bytes[index + 1] == b'}'
+                && bytes[index + 2] == b'{'
+                && bytes[index + 3] == b'}'
+            {
+                panic!("a pattern can't have adjacent {{}}{{}} fields");
+            }
+            index += 1;
+        }
+        Pattern { pattern }
+    }
+
+    pub fn matches<'a>(&self, line: Line<'a>) -> Result<Fields<'a>, ParseError> {
+        let mismatch = |rest: &str, literal: &str| {
+            line.error_at(rest, format!("doesn't match {:?}", self.pattern))
+                .expected(format!("{:?}", literal))
+        };
+
+        let mut literals = self.pattern.split("{}");
+        let first = literals.next().unwrap_or("");
+        let mut rest = line
+            .text
+            .strip_prefix(first)
+            .ok_or_else(|| mismatch(line.text, first))?;
+
+        let mut fields = Vec::new();
+        for literal in literals {
+            let end = match literal {
+                "" => rest.len(),
+                _ => rest.find(literal).ok_or_else(|| mismatch(rest, literal))?,
+            };
+            fields.push(&rest[..end]);
+            rest = &rest[end + literal.len()..];
+        }
+        if !rest.is_empty() {
+            return Err(line
+                .error_at(rest, format!("doesn't match {:?}", self.pattern))
+                .expected("the end of the line"));
+        }
+
+        Ok(Fields { line, fields })
+    }
+}
+
+/// The fields a `Pattern` matched, in order.
+#[derive(Debug, Clone)]
+pub struct Fields<'a> {
+    pub line: Line<'a>,
+    fields: Vec<&'a str>,
+}
+
+impl<'a> Fields<'a> {
+    pub fn get(&self, index: usize) -> &'a str {
+        self.fields[index]
+    }
+
+    pub fn parse<T: FromStr>(&self, index: usize, expected: &str) -> Result<T, ParseError> {
+        self.line.parse(self.fields[index], expected)
+    }
+
+    /// Parses every piece of a field between `separator`s as `expected`.
+    pub fn split<T: FromStr>(
+        &self,
+        index: usize,
+        separator: &str,
+        expected: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        self.fields[index]
+            .split(separator)
+            .map(|piece| self.line.parse(piece, expected))
+            .collect()
+    }
+
+    /// Parses every whitespace separated word in a field as `expected`.
+    pub fn words<T: FromStr>(&self, index: usize, expected: &str) -> Result<Vec<T>, ParseError> {
+        self.fields[index]
+            .split_whitespace()
+            .map(|word| self.line.parse(word, expected))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lines, sections, Line, Pattern};
+    use crate::ParseError;
+
+    fn line(text: &str) -> Line<'_> {
+        Line { number: 3, text }
+    }
+
+    #[test]
+    fn lines_are_numbered_from_one() {
+        let numbered: Vec<_> = lines("a\n\nb\n")
+            .map(|line| (line.number, line.text))
+            .collect();
+        assert_eq!(numbered, [(1, "a"), (2, ""), (3, "b")]);
+    }
+
+    #[test]
+    fn sections_between_blank_lines() {
+        let input = "\n \na\nb\n\n\n\nc\n\n";
+        let grouped: Vec<Vec<_>> = sections(input)
+            .into_iter()
+            .map(|section| {
+                section
+                    .iter()
+                    .map(|line| (line.number, line.text))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(grouped, [vec![(3, "a"), (4, "b")], vec![(8, "c")]]);
+        assert!(sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn integers_with_signs() {
+        assert_eq!(
+            line("p=-3,4 v=+2,-0").integers::<i32>().unwrap(),
+            [-3, 4, 2, 0]
+        );
+        // A minus straight after a number still starts a negative number.
+        assert_eq!(line("5-3 - 1").integers::<i32>().unwrap(), [5, -3, 1]);
+        assert!(line("no numbers - here")
+            .integers::<i32>()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn integer_overflow() {
+        let error = line("a=1, b=300").integers::<u8>().unwrap_err();
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.column, Some(8));
+        assert_eq!(error.found.as_deref(), Some("300"));
+
+        let error = line("x=-129").integers::<i8>().unwrap_err();
+        assert_eq!(error.column, Some(3));
+        assert_eq!(error.found.as_deref(), Some("-129"));
+    }
+
+    #[test]
+    fn pattern_with_trailing_literal() {
+        const CALL: Pattern = Pattern::new("mul({},{})");
+        let fields = CALL.matches(line("mul(12,3)")).unwrap();
+        assert_eq!((fields.get(0), fields.get(1)), ("12", "3"));
+
+        let error = CALL.matches(line("mul(12,3")).unwrap_err();
+        assert_eq!(error.column, Some(8));
+        assert_eq!(error.expected.as_deref(), Some("\")\""));
+
+        let error = CALL.matches(line("mul(12,3) ")).unwrap_err();
+        assert_eq!(error.column, Some(10));
+        assert_eq!(error.expected.as_deref(), Some("the end of the line"));
+    }
+
+    #[test]
+    fn pattern_literal_mismatch() {
+        const ROBOT: Pattern = Pattern::new("p={},{} v={},{}");
+        let error = ROBOT.matches(line("p=0,4 w=3,-3")).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                column: Some(5),
+                ..ParseError::at_line(3, "doesn't match \"p={},{} v={},{}\"")
+                    .found("4 w=3,-3")
+                    .expected("\" v=\"")
+            }
+        );
+
+        let error = ROBOT.matches(line("q=0,4 v=3,-3")).unwrap_err();
+        assert_eq!(error.column, Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "adjacent")]
+    fn adjacent_fields() {
+        Pattern::new("{}{}-{}");
+    }
+
+    #[test]
+    fn field_split_with_bad_token() {
+        const EQUATION: Pattern = Pattern::new("{}: {}");
+        let fields = EQUATION.matches(line("190: 10 19")).unwrap();
+        assert_eq!(fields.words::<u64>(1, "a number").unwrap(), [10, 19]);
+
+        let fields = EQUATION.matches(line("7: 1,x2,3")).unwrap();
+        let error = fields.split::<u64>(1, ",", "a number").unwrap_err();
+        assert_eq!(error.column, Some(6));
+        assert_eq!(error.found.as_deref(), Some("x2"));
+        assert_eq!(error.expected.as_deref(), Some("a number"));
+        assert_eq!(error.message, "invalid value");
+    }
+}
@@ -0,0 +1,223 @@
+//! Text rendering for grids, with optional ANSI colour and overlays.
+//!
+//! Any `Grid<T>` whose cells implement `CellGlyph` can be printed directly with
+//! `{}`. For anything more, `Grid::render` returns a `Render` which layers
+//! paths, highlighted points or coloured regions on top of the cells. Later
+//! layers win where they overlap.
+
+use crate::{Direction, Grid, Point};
+use std::{collections::HashMap, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Colour {
+    pub const BLACK: Colour = Colour::new(0, 0, 0);
+    pub const WHITE: Colour = Colour::new(255, 255, 255);
+    pub const GREY: Colour = Colour::new(128, 128, 128);
+    pub const RED: Colour = Colour::new(220, 50, 47);
+    pub const GREEN: Colour = Colour::new(64, 160, 43);
+    pub const YELLOW: Colour = Colour::new(223, 142, 29);
+    pub const BLUE: Colour = Colour::new(30, 102, 245);
+    pub const MAGENTA: Colour = Colour::new(211, 54, 130);
+    pub const CYAN: Colour = Colour::new(23, 146, 153);
+
+    /// Cycled through by `Render::regions`.
+    pub const PALETTE: [Colour; 7] = [
+        Colour::RED,
+        Colour::GREEN,
+        Colour::YELLOW,
+        Colour::BLUE,
+        Colour::MAGENTA,
+        Colour::CYAN,
+        Colour::GREY,
+    ];
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Colour {
+        Colour { r, g, b }
+    }
+}
+
+/// How a single cell is drawn.
+pub trait CellGlyph {
+    fn glyph(&self) -> char;
+
+    fn colour(&self) -> Option<Colour> {
+        None
+    }
+}
+
+impl CellGlyph for char {
+    fn glyph(&self) -> char {
+        *self
+    }
+}
+
+// What a layer replaces at a point; `None` keeps whatever is underneath.
+#[derive(Debug, Clone, Copy, Default)]
+struct Overlay {
+    glyph: Option<char>,
+    colour: Option<Colour>,
+}
+
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    overlays: HashMap<Point, Overlay>,
+    colour: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn render(&self) -> Render<'_, T> {
+        Render {
+            grid: self,
+            overlays: HashMap::new(),
+            colour: false,
+        }
+    }
+}
+
+impl<'a, T> Render<'a, T> {
+    /// Turns ANSI colour escapes on or off. Off by default.
+    pub fn colour(mut self, enabled: bool) -> Self {
+        self.colour = enabled;
+        self
+    }
+
+    /// Replaces the glyph at each point, keeping its colour.
+    pub fn glyphs(mut self, glyphs: impl IntoIterator<Item = (Point, char)>) -> Self {
+        for (point, glyph) in glyphs {
+            self.overlays.entry(point).or_default().glyph = Some(glyph);
+        }
+        self
+    }
+
+    /// Colours each point, keeping its glyph.
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, colour: Colour) -> Self {
+        for point in points {
+            self.overlays.entry(point).or_default().colour = Some(colour);
+        }
+        self
+    }
+
+    /// Draws an arrow for each step of the path, coloured with `colour`.
+    pub fn path(
+        mut self,
+        path: impl IntoIterator<Item = (Point, Direction)>,
+        colour: Colour,
+    ) -> Self {
+        for (point, direction) in path {
+            self.overlays.insert(
+                point,
+                Overlay {
+                    glyph: Some(direction.to_char()),
+                    colour: Some(colour),
+                },
+            );
+        }
+        self
+    }
+
+    /// Gives each region its own colour from `Colour::PALETTE`.
+    pub fn regions<R>(mut self, regions: impl IntoIterator<Item = R>) -> Self
+    where
+        R: IntoIterator<Item = Point>,
+    {
+        for (region, colour) in regions.into_iter().zip(Colour::PALETTE.iter().cycle()) {
+            self = self.highlight(region, *colour);
+        }
+        self
+    }
+}
+
+impl<T: CellGlyph> fmt::Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for (x, cell) in row.iter().enumerate() {
+                let overlay = self.overlays.get(&Point::new(x, y));
+                let glyph = overlay.and_then(|overlay| overlay.glyph);
+                let colour = overlay.and_then(|overlay| overlay.colour);
+                let glyph = glyph.unwrap_or_else(|| cell.glyph());
+
+                match colour.or_else(|| cell.colour()).filter(|_| self.colour) {
+                    Some(Colour { r, g, b }) => {
+                        write!(f, "\x1b[38;2;{};{};{}m{}\x1b[0m", r, g, b, glyph)?
+                    }
+                    None => write!(f, "{}", glyph)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: CellGlyph> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Colour;
+    use crate::{Direction, Grid, Point};
+    use std::convert::Infallible;
+
+    fn letters(text: &str) -> Grid<char> {
+        Grid::parse(text, |letter, _| Ok::<_, Infallible>(letter)).unwrap()
+    }
+
+    #[test]
+    fn plain_text_without_colour() {
+        let grid = letters("ab\ncd\n");
+        assert_eq!(grid.to_string(), "ab\ncd");
+        let rendered = grid
+            .render()
+            .highlight([Point::new(0, 0)], Colour::RED)
+            .to_string();
+        assert_eq!(rendered, "ab\ncd");
+    }
+
+    #[test]
+    fn ansi_colour_escapes() {
+        let grid = letters("ab\n");
+        let rendered = grid
+            .render()
+            .highlight([Point::new(1, 0)], Colour::new(1, 2, 3))
+            .colour(true)
+            .to_string();
+        assert_eq!(rendered, "a\x1b[38;2;1;2;3mb\x1b[0m");
+    }
+
+    #[test]
+    fn path_over_highlight_over_glyph() {
+        let grid = letters("abc\n");
+        let everywhere = [Point::new(0, 0), Point::new(1, 0), Point::new(2, 0)];
+        let rendered = grid
+            .render()
+            .glyphs(everywhere.map(|point| (point, '#')))
+            .highlight([Point::new(1, 0), Point::new(2, 0)], Colour::RED)
+            .path([(Point::new(2, 0), Direction::Right)], Colour::BLUE)
+            .colour(true)
+            .to_string();
+        assert_eq!(
+            rendered,
+            "#\x1b[38;2;220;50;47m#\x1b[0m\x1b[38;2;30;102;245m>\x1b[0m"
+        );
+
+        // A highlight after the path recolours its arrow but keeps the glyph.
+        let rendered = grid
+            .render()
+            .path([(Point::new(0, 0), Direction::Up)], Colour::BLUE)
+            .highlight([Point::new(0, 0)], Colour::RED)
+            .colour(true)
+            .to_string();
+        assert_eq!(rendered, "\x1b[38;2;220;50;47m^\x1b[0mbc");
+    }
+}
@@ -0,0 +1,591 @@
+//! Shortest-path searches over any state type.
+//!
+//! Each search comes in two flavours: the plain one stops at the first goal it
+//! reaches, while the `_all` one keeps going until every goal at the optimal
+//! cost has been found and remembers every predecessor on an optimal path, so
+//! the shortest paths can be counted or enumerated afterwards.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, C> {
+    pub cost: C,
+    /// One optimal path, from the start to `goals[0]` inclusive.
+    pub path: Vec<S>,
+    /// Every goal state reached at the optimal cost. Only the `_all` searches
+    /// look for more than one.
+    pub goals: Vec<S>,
+    /// Only filled in by the `_all` searches.
+    pub predecessors: Option<Predecessors<S>>,
+}
+
+/// For each state reached on an optimal path, the states it can be reached
+/// from at its optimal cost.
+#[derive(Debug, Clone)]
+pub struct Predecessors<S> {
+    parents: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> Predecessors<S> {
+    pub fn parents(&self, state: &S) -> &[S] {
+        self.parents.get(state).map_or(&[], |parents| parents)
+    }
+
+    /// Every state that lies on at least one optimal path to one of `goals`.
+    pub fn states_on_paths(&self, goals: &[S]) -> HashSet<S> {
+        let mut states: HashSet<S> = goals.iter().cloned().collect();
+        let mut to_visit: Vec<S> = goals.to_vec();
+
+        while let Some(state) = to_visit.pop() {
+            for parent in self.parents(&state) {
+                if states.insert(parent.clone()) {
+                    to_visit.push(parent.clone());
+                }
+            }
+        }
+        states
+    }
+
+    /// The number of distinct optimal paths ending at any of `goals`.
+    pub fn count_paths(&self, goals: &[S]) -> u64 {
+        // The searches never give a state a parent that would make a cycle, so
+        // a depth-first walk back from the goals finishes each state's parents
+        // before the state itself. It keeps its own stack, as optimal paths can
+        // be far longer than the call stack is deep.
+        let mut counts: HashMap<S, u64> = HashMap::new();
+        let mut to_visit: Vec<(S, bool)> = goals.iter().map(|goal| (goal.clone(), false)).collect();
+        while let Some((state, parents_counted)) = to_visit.pop() {
+            if counts.contains_key(&state) {
+                continue;
+            }
+            let parents = self.parents(&state);
+            if parents_counted {
+                // Only the start has no parents.
+                let count = match parents {
+                    [] => 1,
+                    _ => parents.iter().map(|parent| counts[parent]).sum(),
+                };
+                counts.insert(state, count);
+            } else {
+                to_visit.push((state.clone(), true));
+                to_visit.extend(parents.iter().map(|parent| (parent.clone(), false)));
+            }
+        }
+        goals.iter().map(|goal| counts[goal]).sum()
+    }
+}
+
+// States are interned so the queues and parent lists only hold indices.
+struct Arena<S> {
+    states: Vec<S>,
+    ids: HashMap<S, usize>,
+    parents: Vec<Vec<usize>>,
+}
+
+impl<S: Clone + Eq + Hash> Arena<S> {
+    fn new() -> Arena<S> {
+        Arena {
+            states: Vec::new(),
+            ids: HashMap::new(),
+            parents: Vec::new(),
+        }
+    }
+
+    // Returns the state's id and whether it was seen for the first time.
+    fn intern(&mut self, state: S) -> (usize, bool) {
+        if let Some(id) = self.ids.get(&state) {
+            return (*id, false);
+        }
+        let id = self.states.len();
+        self.ids.insert(state.clone(), id);
+        self.states.push(state);
+        self.parents.push(Vec::new());
+        (id, true)
+    }
+
+    fn into_result<C>(self, cost: C, goals: Vec<usize>, keep_all: bool) -> SearchResult<S, C> {
+        let mut path = vec![goals[0]];
+        while let Some(parent) = self.parents[*path.last().unwrap()].first() {
+            path.push(*parent);
+        }
+        path.reverse();
+
+        let predecessors = keep_all.then(|| Predecessors {
+            parents: self
+                .parents
+                .iter()
+                .enumerate()
+                .filter(|(_, parents)| !parents.is_empty())
+                .map(|(id, parents)| {
+                    let parents = parents.iter().map(|id| self.states[*id].clone());
+                    (self.states[id].clone(), parents.collect())
+                })
+                .collect(),
+        });
+
+        SearchResult {
+            cost,
+            path: path.iter().map(|id| self.states[*id].clone()).collect(),
+            goals: goals.iter().map(|id| self.states[*id].clone()).collect(),
+            predecessors,
+        }
+    }
+}
+
+/// Breadth-first search where every step costs one.
+pub fn bfs<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(start, successors, is_goal, false)
+}
+
+pub fn bfs_all<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(start, successors, is_goal, true)
+}
+
+fn breadth_first<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    keep_all: bool,
+) -> Option<SearchResult<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut arena = Arena::new();
+    let mut depths: Vec<usize> = Vec::new();
+    let mut queue = VecDeque::new();
+    let mut goals = Vec::new();
+    let mut goal_depth = None;
+
+    let (start_id, _) = arena.intern(start);
+    depths.push(0);
+    queue.push_back(start_id);
+
+    while let Some(id) = queue.pop_front() {
+        let depth = depths[id];
+        if goal_depth.is_some_and(|goal_depth| depth > goal_depth) {
+            break;
+        }
+        if is_goal(&arena.states[id]) {
+            goal_depth = Some(depth);
+            goals.push(id);
+            if !keep_all {
+                break;
+            }
+            continue;
+        }
+
+        let state = arena.states[id].clone();
+        for next in successors(&state) {
+            let (next_id, is_new) = arena.intern(next);
+            if is_new {
+                depths.push(depth + 1);
+                arena.parents[next_id].push(id);
+                queue.push_back(next_id);
+            } else if keep_all && depths[next_id] == depth + 1 {
+                arena.parents[next_id].push(id);
+            }
+        }
+    }
+
+    goal_depth.map(|depth| arena.into_result(depth, goals, keep_all))
+}
+
+/// Dijkstra's algorithm. `successors` yields each neighbouring state with the
+/// cost of moving to it.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, successors, |_| C::default(), is_goal, false)
+}
+
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, successors, |_| C::default(), is_goal, true)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost. A state
+/// found more cheaply after it's been expanded is expanded again, so that's
+/// enough for `astar`, but `astar_all` needs the heuristic to be consistent
+/// too, or some of the optimal paths can be missed.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, successors, heuristic, is_goal, false)
+}
+
+pub fn astar_all<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, successors, heuristic, is_goal, true)
+}
+
+// Whether `ancestor` is `state` or one of its parents' ancestors. Parents
+// never cost more than their children, so only the ones at `state`'s own cost
+// need following.
+fn is_ancestor<S, C: Copy + Eq>(
+    arena: &Arena<S>,
+    costs: &[C],
+    ancestor: usize,
+    state: usize,
+) -> bool {
+    let mut seen = HashSet::from([state]);
+    let mut to_visit = vec![state];
+    while let Some(id) = to_visit.pop() {
+        if id == ancestor {
+            return true;
+        }
+        for parent in &arena.parents[id] {
+            if costs[*parent] == costs[state] && seen.insert(*parent) {
+                to_visit.push(*parent);
+            }
+        }
+    }
+    false
+}
+
+fn best_first<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    keep_all: bool,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut arena = Arena::new();
+    let mut costs: Vec<C> = Vec::new();
+    let mut closed: Vec<bool> = Vec::new();
+    let mut heap = BinaryHeap::new();
+    let mut goals = Vec::new();
+    let mut goal_cost = None;
+
+    let (start_id, _) = arena.intern(start);
+    costs.push(C::default());
+    closed.push(false);
+    heap.push(Reverse((
+        heuristic(&arena.states[start_id]),
+        C::default(),
+        start_id,
+    )));
+
+    while let Some(Reverse((priority, cost, id))) = heap.pop() {
+        if goal_cost.is_some_and(|goal_cost| priority > goal_cost) {
+            break;
+        }
+        // A cheaper route to this state was queued after this entry.
+        if cost > costs[id] || (closed[id] && cost == costs[id]) {
+            continue;
+        }
+        closed[id] = true;
+
+        if is_goal(&arena.states[id]) {
+            goal_cost = Some(cost);
+            goals.push(id);
+            if !keep_all {
+                break;
+            }
+            continue;
+        }
+
+        let state = arena.states[id].clone();
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let (next_id, is_new) = arena.intern(next);
+            if is_new {
+                costs.push(next_cost);
+                closed.push(false);
+            } else if next_id == start_id || next_cost > costs[next_id] {
+                continue;
+            } else if next_cost == costs[next_id] {
+                // Only a zero-cost step can lead back to one of this state's
+                // own ancestors, and that parent would make a cycle.
+                if keep_all && !(next_cost == cost && is_ancestor(&arena, &costs, next_id, id)) {
+                    arena.parents[next_id].push(id);
+                }
+                continue;
+            }
+
+            // Reopens the state if it had already been expanded.
+            closed[next_id] = false;
+            costs[next_id] = next_cost;
+            arena.parents[next_id] = vec![id];
+            let priority = next_cost + heuristic(&arena.states[next_id]);
+            heap.push(Reverse((priority, next_cost, next_id)));
+        }
+    }
+
+    goal_cost.map(|cost| arena.into_result(cost, goals, keep_all))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, astar_all, bfs, bfs_all, dijkstra, dijkstra_all};
+    use std::collections::HashSet;
+
+    /// The successors of each state in a directed graph of `(from, to, cost)`
+    /// edges.
+    fn graph(edges: &[(char, char, u32)]) -> impl Fn(&char) -> Vec<(char, u32)> + '_ {
+        |state| {
+            edges
+                .iter()
+                .filter(|(from, _, _)| from == state)
+                .map(|(_, to, cost)| (*to, *cost))
+                .collect()
+        }
+    }
+
+    fn unweighted(edges: &[(char, char, u32)]) -> impl Fn(&char) -> Vec<char> + '_ {
+        let successors = graph(edges);
+        move |state| successors(state).into_iter().map(|(to, _)| to).collect()
+    }
+
+    // Two shortest routes S-A-G and S-B-G, and a longer one through C and D.
+    const DIAMOND: [(char, char, u32); 7] = [
+        ('S', 'A', 1),
+        ('S', 'B', 1),
+        ('S', 'C', 1),
+        ('A', 'G', 1),
+        ('B', 'G', 1),
+        ('C', 'D', 1),
+        ('D', 'G', 1),
+    ];
+
+    // Cheapest to G for 4 by S-A-C-G or S-B-C-G, with a direct edge costing 5.
+    const WEIGHTED: [(char, char, u32); 6] = [
+        ('S', 'A', 1),
+        ('S', 'B', 2),
+        ('A', 'C', 2),
+        ('B', 'C', 1),
+        ('C', 'G', 1),
+        ('S', 'G', 5),
+    ];
+
+    #[test]
+    fn bfs_finds_a_shortest_path() {
+        let result = bfs('S', unweighted(&DIAMOND), |state| *state == 'G').unwrap();
+        assert_eq!(result.cost, 2);
+        assert_eq!(result.path.len(), 3);
+        assert_eq!(result.path.first(), Some(&'S'));
+        assert_eq!(result.path.last(), Some(&'G'));
+        assert!(result.predecessors.is_none());
+    }
+
+    #[test]
+    fn bfs_all_keeps_every_shortest_path() {
+        let result = bfs_all('S', unweighted(&DIAMOND), |state| *state == 'G').unwrap();
+        let predecessors = result.predecessors.unwrap();
+        assert_eq!(result.cost, 2);
+        assert_eq!(result.goals, ['G']);
+        assert_eq!(predecessors.count_paths(&result.goals), 2);
+        assert_eq!(
+            predecessors.states_on_paths(&result.goals),
+            HashSet::from(['S', 'A', 'B', 'G'])
+        );
+        assert!(predecessors.parents(&'S').is_empty());
+    }
+
+    #[test]
+    fn dijkstra_finds_the_cheapest_path() {
+        let result = dijkstra('S', graph(&WEIGHTED), |state| *state == 'G').unwrap();
+        assert_eq!(result.cost, 4);
+        assert_eq!(result.path.len(), 4);
+        assert_eq!(result.path[2], 'C');
+    }
+
+    #[test]
+    fn dijkstra_all_keeps_every_cheapest_path() {
+        let result = dijkstra_all('S', graph(&WEIGHTED), |state| *state == 'G').unwrap();
+        let predecessors = result.predecessors.unwrap();
+        assert_eq!(result.cost, 4);
+        assert_eq!(predecessors.count_paths(&result.goals), 2);
+        let mut parents = predecessors.parents(&'C').to_vec();
+        parents.sort();
+        assert_eq!(parents, ['A', 'B']);
+        assert_eq!(predecessors.parents(&'G'), ['C']);
+    }
+
+    #[test]
+    fn every_goal_at_the_best_cost() {
+        let edges = [('S', 'X', 2), ('S', 'Y', 2), ('S', 'Z', 3)];
+        let is_goal = |state: &char| "XYZ".contains(*state);
+        let mut goals = dijkstra_all('S', graph(&edges), is_goal).unwrap().goals;
+        goals.sort();
+        assert_eq!(goals, ['X', 'Y']);
+        assert_eq!(
+            dijkstra('S', graph(&edges), is_goal).unwrap().goals.len(),
+            1
+        );
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let is_goal = |state: &char| *state == 'Z';
+        assert!(bfs('S', unweighted(&DIAMOND), is_goal).is_none());
+        assert!(bfs_all('S', unweighted(&DIAMOND), is_goal).is_none());
+        assert!(dijkstra('S', graph(&WEIGHTED), is_goal).is_none());
+        assert!(dijkstra_all('S', graph(&WEIGHTED), is_goal).is_none());
+        assert!(astar('S', graph(&WEIGHTED), |_| 0, is_goal).is_none());
+        assert!(astar_all('S', graph(&WEIGHTED), |_| 0, is_goal).is_none());
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        // A 6x6 grid with a wall to go around, where stepping down costs 2.
+        let walls = [(2, 0), (2, 1), (2, 2), (2, 3), (4, 5), (4, 4), (4, 3)];
+        let successors = |&(x, y): &(i32, i32)| {
+            [(1, 0, 1), (-1, 0, 1), (0, 1, 2), (0, -1, 1)]
+                .into_iter()
+                .map(move |(dx, dy, cost)| ((x + dx, y + dy), cost))
+                .filter(|((x, y), _)| (0..6).contains(x) && (0..6).contains(y))
+                .filter(|(point, _)| !walls.contains(point))
+                .collect::<Vec<_>>()
+        };
+
+        for goal in [(5, 5), (5, 0), (3, 4), (0, 5)] {
+            let is_goal = |state: &(i32, i32)| *state == goal;
+            let manhattan =
+                |&(x, y): &(i32, i32)| (goal.0 - x).unsigned_abs() + (goal.1 - y).unsigned_abs();
+            let expected = dijkstra_all((0, 0), successors, is_goal).unwrap();
+            let found = astar((0, 0), successors, manhattan, is_goal).unwrap();
+            let found_all = astar_all((0, 0), successors, manhattan, is_goal).unwrap();
+
+            assert_eq!(found.cost, expected.cost);
+            assert_eq!(
+                found.path.len(),
+                found.path.iter().collect::<HashSet<_>>().len()
+            );
+            assert_eq!(found_all.cost, expected.cost);
+            assert_eq!(
+                found_all
+                    .predecessors
+                    .unwrap()
+                    .count_paths(&found_all.goals),
+                expected.predecessors.unwrap().count_paths(&expected.goals)
+            );
+        }
+    }
+
+    #[test]
+    fn zero_cost_cycle() {
+        let edges = [('S', 'A', 1), ('A', 'B', 0), ('B', 'A', 0), ('B', 'G', 1)];
+        let result = dijkstra_all('S', graph(&edges), |state| *state == 'G').unwrap();
+        let predecessors = result.predecessors.unwrap();
+        assert_eq!(result.cost, 2);
+        assert_eq!(result.path, ['S', 'A', 'B', 'G']);
+        assert_eq!(predecessors.parents(&'A'), ['S']);
+        assert_eq!(predecessors.count_paths(&result.goals), 1);
+    }
+
+    #[test]
+    fn zero_cost_step_to_an_expanded_state() {
+        // S-A-G and S-B-A-G both cost 2, and A is expanded before B.
+        let edges = [('S', 'A', 1), ('S', 'B', 1), ('B', 'A', 0), ('A', 'G', 1)];
+        let result = dijkstra_all('S', graph(&edges), |state| *state == 'G').unwrap();
+        let predecessors = result.predecessors.unwrap();
+        assert_eq!(result.cost, 2);
+        assert_eq!(predecessors.parents(&'A'), ['S', 'B']);
+        assert_eq!(predecessors.count_paths(&result.goals), 2);
+    }
+
+    #[test]
+    fn astar_with_an_inconsistent_heuristic() {
+        // h(A) doesn't overestimate, but makes C look best through B first.
+        let edges = [
+            ('S', 'A', 1),
+            ('S', 'B', 1),
+            ('A', 'C', 1),
+            ('B', 'C', 2),
+            ('C', 'G', 3),
+        ];
+        let heuristic = |state: &char| if *state == 'A' { 4 } else { 0 };
+        let result = astar('S', graph(&edges), heuristic, |state| *state == 'G').unwrap();
+        assert_eq!(result.cost, 5);
+        assert_eq!(result.path, ['S', 'A', 'C', 'G']);
+    }
+
+    #[test]
+    fn counting_very_long_paths() {
+        const LENGTH: u32 = 200_000;
+        let successors = |state: &u32| match *state < LENGTH {
+            true => vec![state + 1],
+            false => vec![],
+        };
+        let result = bfs_all(0, successors, |state| *state == LENGTH).unwrap();
+        assert_eq!(result.cost, LENGTH as usize);
+        assert_eq!(result.predecessors.unwrap().count_paths(&result.goals), 1);
+    }
+
+    #[test]
+    fn counting_paths_through_a_lattice() {
+        // Every monotone path across a 10x10 lattice, which is 20 choose 10.
+        let successors = |&(x, y): &(u32, u32)| {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|(x, y)| *x <= 10 && *y <= 10)
+                .map(|state| (state, 1))
+                .collect::<Vec<_>>()
+        };
+        let result = dijkstra_all((0, 0), successors, |state| *state == (10, 10)).unwrap();
+        let predecessors = result.predecessors.unwrap();
+        assert_eq!(predecessors.count_paths(&result.goals), 184_756);
+
+        assert_eq!(predecessors.count_paths(&[(10, 0)]), 1);
+        assert_eq!(predecessors.count_paths(&[(1, 1)]), 2);
+    }
+}
@@ -0,0 +1,179 @@
+//! The interface every day implements so the `aoc` runner can drive it.
+
+use crate::{testing::Example, GridParseError, GridParseErrorKind};
+use std::{error::Error, fmt};
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    /// The examples from the puzzle text, checked by `testing::check_examples`.
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// Turns the puzzle text into `Input`. No file I/O happens here, so the
+    /// same parsing serves real inputs, examples and benchmarks alike.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::NotImplemented
+    }
+
+    /// Prints or writes out whatever helps to see what the solution did. Only
+    /// `aoc run --visualise` calls this, so tests and benchmarks time the parts
+    /// alone.
+    fn visualise(_input: &Self::Input) {}
+}
+
+/// What a part returns, whatever type the day computes it in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    NotImplemented,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(integer) => write!(f, "{}", integer),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(integer: $integer) -> Answer {
+                    Answer::Integer(integer as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// Why an input couldn't be parsed, and where, as precisely as the parser
+/// knows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based, if the problem can be pinned to a line.
+    pub line: Option<usize>,
+    /// 1-based, if the problem can be pinned to a character in the line.
+    pub column: Option<usize>,
+    /// The text that couldn't be parsed.
+    pub found: Option<String>,
+    /// What should have been there instead, e.g. "a number".
+    pub expected: Option<String>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: None,
+            column: None,
+            found: None,
+            expected: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(line: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: Some(line),
+            ..ParseError::new(message)
+        }
+    }
+
+    /// Points at `token`, which should be a slice of `line`, the `line_number`th
+    /// line of the input. The column is left out if it isn't.
+    pub fn at_token(
+        line_number: usize,
+        line: &str,
+        token: &str,
+        message: impl Into<String>,
+    ) -> ParseError {
+        let line_start = line.as_ptr() as usize;
+        let token_start = token.as_ptr() as usize;
+        let column = (line_start..=line_start + line.len())
+            .contains(&token_start)
+            .then(|| line[..token_start - line_start].chars().count() + 1);
+        ParseError {
+            column,
+            ..ParseError::at_line(line_number, message).found(token)
+        }
+    }
+
+    pub fn found(self, found: impl Into<String>) -> ParseError {
+        ParseError {
+            found: Some(found.into()),
+            ..self
+        }
+    }
+
+    pub fn expected(self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            expected: Some(expected.into()),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            _ => {}
+        }
+        write!(f, "{}", self.message)?;
+        match (&self.expected, &self.found) {
+            (Some(expected), Some(found)) => {
+                write!(f, ", expected {}, found {:?}", expected, found)
+            }
+            (Some(expected), None) => write!(f, ", expected {}", expected),
+            (None, Some(found)) => write!(f, ", found {:?}", found),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl<E: fmt::Display> From<GridParseError<E>> for ParseError {
+    fn from(error: GridParseError<E>) -> ParseError {
+        let at = ParseError {
+            column: Some(error.column),
+            ..ParseError::at_line(error.line, "")
+        };
+        match error.kind {
+            GridParseErrorKind::InvalidCell { character, error } => ParseError {
+                message: error.to_string(),
+                ..at.found(character)
+            },
+            GridParseErrorKind::RaggedRow { expected_width } => ParseError {
+                message: "ragged row".to_string(),
+                ..at.expected(format!("a row of width {}", expected_width))
+            },
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Matches small 2D shapes of letters against a grid, in every rotation and
+//! reflection, so shapes like day 4's X-MAS cross are written down as data.
+
+use crate::{Grid, ParseError, Point};
+use std::convert::Infallible;
+
+/// The character that matches any cell.
+pub const WILDCARD: char = '.';
+
+/// A shape with `.` for cells that can be anything, written one row per
+/// line or with rows separated by `/`, e.g. `"M.S/.A./M.S"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stencil {
+    /// Every distinct rotation and reflection, starting with the shape as
+    /// written.
+    orientations: Vec<Shape>,
+}
+
+pub type Shape = Grid<Option<char>>;
+
+/// Where one orientation of a stencil fits in the grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub top_left: Point,
+    /// Indexes `Stencil::orientations`.
+    pub orientation: usize,
+    /// The grid points under the stencil's non-wildcard cells, in reading
+    /// order of the placed shape.
+    pub points: Vec<Point>,
+}
+
+impl Stencil {
+    pub fn parse(spec: &str) -> Result<Stencil, ParseError> {
+        let rows = spec.replace('/', "\n");
+        let shape = Grid::parse(&rows, |cell, _| {
+            Ok::<_, Infallible>((cell != WILDCARD).then_some(cell))
+        })?;
+
+        let mut orientations: Vec<Shape> = Vec::new();
+        let mut rotated = shape;
+        for _ in 0..4 {
+            for candidate in [rotated.clone(), reflect(&rotated)] {
+                if !orientations.contains(&candidate) {
+                    orientations.push(candidate);
+                }
+            }
+            rotated = rotate(&rotated);
+        }
+        Ok(Stencil { orientations })
+    }
+
+    /// Only matches the shape as written, without rotating or reflecting it.
+    pub fn fixed(self) -> Stencil {
+        Stencil {
+            orientations: self.orientations.into_iter().take(1).collect(),
+        }
+    }
+
+    pub fn orientations(&self) -> &[Shape] {
+        &self.orientations
+    }
+
+    /// Every placement of every orientation, in reading order of where they
+    /// start. A shape with symmetries is only counted once per placement.
+    pub fn find(&self, grid: &Grid<char>) -> Vec<Placement> {
+        let mut placements = Vec::new();
+        for top_left in grid.points() {
+            for (orientation, shape) in self.orientations.iter().enumerate() {
+                if let Some(points) = place(shape, grid, top_left) {
+                    placements.push(Placement {
+                        top_left,
+                        orientation,
+                        points,
+                    });
+                }
+            }
+        }
+        placements
+    }
+
+    pub fn count(&self, grid: &Grid<char>) -> usize {
+        self.find(grid).len()
+    }
+}
+
+fn place(shape: &Shape, grid: &Grid<char>, top_left: Point) -> Option<Vec<Point>> {
+    let mut points = Vec::new();
+    for (offset, cell) in shape.iter() {
+        let point = Point::new(top_left.x + offset.x, top_left.y + offset.y);
+        let letter = grid.get(point)?;
+        match cell {
+            Some(wanted) if wanted != letter => return None,
+            Some(_) => points.push(point),
+            None => {}
+        }
+    }
+    Some(points)
+}
+
+/// A quarter turn clockwise.
+fn rotate(shape: &Shape) -> Shape {
+    let (width, height) = (shape.height(), shape.width());
+    let cells = (0..height)
+        .flat_map(|y| (0..width).map(move |x| Point::new(y, shape.height() - 1 - x)))
+        .map(|from| shape[from])
+        .collect();
+    Grid::new(width, height, cells)
+}
+
+/// Mirrored left to right.
+fn reflect(shape: &Shape) -> Shape {
+    let cells = shape
+        .points()
+        .map(|point| shape[Point::new(shape.width() - 1 - point.x, point.y)])
+        .collect();
+    Grid::new(shape.width(), shape.height(), cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Placement, Stencil};
+    use crate::{Grid, Point};
+    use std::convert::Infallible;
+
+    fn letters(text: &str) -> Grid<char> {
+        Grid::parse(text, |letter, _| Ok::<_, Infallible>(letter)).unwrap()
+    }
+
+    #[test]
+    fn stencil_orientations() {
+        // The X-MAS cross only differs by which sides the Ms are on.
+        assert_eq!(
+            Stencil::parse("M.S/.A./M.S").unwrap().orientations().len(),
+            4
+        );
+        assert_eq!(Stencil::parse("AB").unwrap().orientations().len(), 4);
+        assert_eq!(Stencil::parse("AB/C.").unwrap().orientations().len(), 8);
+        assert_eq!(
+            Stencil::parse(".A./AAA/.A.").unwrap().orientations().len(),
+            1
+        );
+        assert!(Stencil::parse("AB/C").is_err());
+    }
+
+    #[test]
+    fn stencil_placements() {
+        let grid = letters("XAB\nBXA\nXXX\n");
+        let stencil = Stencil::parse("A/B").unwrap();
+        assert_eq!(
+            stencil.find(&grid),
+            [
+                Placement {
+                    top_left: Point::new(1, 0),
+                    orientation: 2,
+                    points: vec![Point::new(1, 0), Point::new(2, 0)],
+                },
+                Placement {
+                    top_left: Point::new(2, 0),
+                    orientation: 3,
+                    points: vec![Point::new(2, 0), Point::new(2, 1)],
+                },
+            ]
+        );
+        assert_eq!(stencil.fixed().count(&grid), 0);
+    }
+}
@@ -0,0 +1,139 @@
+//! Checks a day against its registered `Solution::EXAMPLES` and, if there is
+//! one, the answer for the real input.
+//!
+//! Real answers are kept in an optional `answers.toml` at the workspace root,
+//! one table per day:
+//!
+//! ```toml
+//! [6]
+//! part1 = 4711
+//! part2 = "4,7,1,1"
+//! ```
+//!
+//! The real input is looked up like any other, see `crate::input`.
+
+use crate::input::{workspace_dir, Inputs, Variant};
+use crate::{Answer, Solution};
+use std::fs::read_to_string;
+
+/// A puzzle example with the answers the puzzle text gives for it. Parts the
+/// example doesn't cover are `None`.
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub const fn new(name: &'static str, input: &'static str) -> Example {
+        Example {
+            name,
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub const fn part1(self, expected: &'static str) -> Example {
+        Example {
+            part1: Some(expected),
+            ..self
+        }
+    }
+
+    pub const fn part2(self, expected: &'static str) -> Example {
+        Example {
+            part2: Some(expected),
+            ..self
+        }
+    }
+}
+
+// Runs each part with an expected answer, describing every mismatch.
+fn check<S: Solution>(
+    name: &str,
+    input: &str,
+    expected: [Option<&str>; 2],
+    failures: &mut Vec<String>,
+) {
+    let input = match S::parse(input) {
+        Ok(input) => input,
+        Err(error) => {
+            failures.push(format!("{}: failed to parse: {}", name, error));
+            return;
+        }
+    };
+
+    let parts: [fn(&S::Input) -> Answer; 2] = [S::part1, S::part2];
+    for (part, (solve, expected)) in parts.iter().zip(expected).enumerate() {
+        let Some(expected) = expected else {
+            continue;
+        };
+        let answer = solve(&input).to_string();
+        if answer != expected {
+            failures.push(format!(
+                "{} part {}: expected {}, got {}",
+                name,
+                part + 1,
+                expected,
+                answer
+            ));
+        }
+    }
+}
+
+fn report(day: u8, failures: Vec<String>) {
+    if !failures.is_empty() {
+        panic!("day {}:\n{}", day, failures.join("\n"));
+    }
+}
+
+/// Panics listing every example part that gives the wrong answer.
+pub fn check_examples<S: Solution>() {
+    let mut failures = Vec::new();
+    for example in S::EXAMPLES {
+        check::<S>(
+            example.name,
+            example.input,
+            [example.part1, example.part2],
+            &mut failures,
+        );
+    }
+    report(S::DAY, failures);
+}
+
+/// Panics if the real input gives a different answer to `answers.toml`. Does
+/// nothing if either the answers or the input are missing.
+pub fn check_answers<S: Solution>() {
+    let answers_path = workspace_dir().join("answers.toml");
+    let Ok(answers) = read_to_string(&answers_path) else {
+        return;
+    };
+    let answers: toml::Table = answers
+        .parse()
+        .unwrap_or_else(|error| panic!("{} is invalid: {}", answers_path.display(), error));
+    let Some(day_answers) = answers.get(&S::DAY.to_string()) else {
+        return;
+    };
+    let Ok(input) = Inputs::default().load::<S>(Variant::Real) else {
+        return;
+    };
+
+    let expected = ["part1", "part2"].map(|part| {
+        day_answers.get(part).map(|answer| match answer {
+            toml::Value::String(text) => text.clone(),
+            other => other.to_string(),
+        })
+    });
+
+    let mut failures = Vec::new();
+    check::<S>(
+        "real input",
+        &input,
+        [expected[0].as_deref(), expected[1].as_deref()],
+        &mut failures,
+    );
+    report(S::DAY, failures);
+}
@@ -0,0 +1,173 @@
+//! Finds words in a grid of letters, in any of the 8 directions.
+//!
+//! The words go into a trie, so a single walk out from each cell in each
+//! direction finds every word starting there, however many words there are.
+
+use crate::{Direction8, Grid, Point};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: BTreeMap<char, usize>,
+    /// The index of the word ending here, if one does.
+    word: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WordSearch {
+    words: Vec<String>,
+    nodes: Vec<Node>,
+    wrap: bool,
+}
+
+/// A word found in the grid, with the point of each of its letters in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordMatch<'a> {
+    pub word: &'a str,
+    pub start: Point,
+    pub direction: Direction8,
+    pub points: Vec<Point>,
+}
+
+impl WordSearch {
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> WordSearch {
+        let mut search = WordSearch {
+            words: Vec::new(),
+            nodes: vec![Node::default()],
+            wrap: false,
+        };
+        for word in words {
+            search.insert(word.as_ref());
+        }
+        search
+    }
+
+    /// Lets words run off one edge of the grid and back in on the opposite
+    /// one.
+    pub fn wrapping(self, wrap: bool) -> WordSearch {
+        WordSearch { wrap, ..self }
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    fn insert(&mut self, word: &str) {
+        let mut node = 0;
+        for letter in word.chars() {
+            node = match self.nodes[node].children.get(&letter) {
+                Some(child) => *child,
+                None => {
+                    self.nodes.push(Node::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(letter, child);
+                    child
+                }
+            };
+        }
+        if node != 0 && self.nodes[node].word.is_none() {
+            self.nodes[node].word = Some(self.words.len());
+            self.words.push(word.to_string());
+        }
+    }
+
+    /// Every occurrence of every word, in reading order of where they start.
+    /// A word that reads the same both ways is found once in each direction.
+    pub fn find(&self, grid: &Grid<char>) -> Vec<WordMatch<'_>> {
+        let mut matches = Vec::new();
+        for start in grid.points() {
+            for direction in Direction8::iter() {
+                self.find_from(grid, start, direction, &mut matches);
+            }
+        }
+        matches
+    }
+
+    fn find_from<'a>(
+        &'a self,
+        grid: &Grid<char>,
+        start: Point,
+        direction: Direction8,
+        matches: &mut Vec<WordMatch<'a>>,
+    ) {
+        let mut node = 0;
+        let mut points = Vec::new();
+        let mut point = Some(start);
+        while let Some(current) = point {
+            let Some(child) = grid
+                .get(current)
+                .and_then(|letter| self.nodes[node].children.get(letter))
+            else {
+                return;
+            };
+            node = *child;
+            points.push(current);
+            if let Some(word) = self.nodes[node].word {
+                matches.push(WordMatch {
+                    word: &self.words[word],
+                    start,
+                    direction,
+                    points: points.clone(),
+                });
+            }
+            point = match self.wrap {
+                true => {
+                    current.wrapping_add_mod(direction.to_vector(), grid.width(), grid.height())
+                }
+                false => current.checked_add(direction.to_vector()),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WordSearch;
+    use crate::{Direction8, Grid, Point};
+    use std::convert::Infallible;
+
+    fn letters(text: &str) -> Grid<char> {
+        Grid::parse(text, |letter, _| Ok::<_, Infallible>(letter)).unwrap()
+    }
+
+    #[test]
+    fn many_words_in_one_pass() {
+        let grid = letters("CATS\nAXOX\nRXXG\n");
+        let search = WordSearch::new(["CAT", "CATS", "CAR", "DOG", "GO"]);
+        let found: Vec<_> = search
+            .find(&grid)
+            .into_iter()
+            .map(|found| (found.word, found.start, found.direction))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("CAT", Point::new(0, 0), Direction8::Right),
+                ("CATS", Point::new(0, 0), Direction8::Right),
+                ("CAR", Point::new(0, 0), Direction8::Down),
+                ("GO", Point::new(3, 2), Direction8::UpLeft),
+            ]
+        );
+    }
+
+    #[test]
+    fn wrapping_around_the_edges() {
+        let grid = letters("SXMA\n....\n");
+        assert!(WordSearch::new(["XMAS"]).find(&grid).is_empty());
+
+        let search = WordSearch::new(["XMAS"]).wrapping(true);
+        let found = search.find(&grid);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].start, Point::new(1, 0));
+        assert_eq!(found[0].direction, Direction8::Right);
+        assert_eq!(
+            found[0].points,
+            [
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(3, 0),
+                Point::new(0, 0)
+            ]
+        );
+    }
+}